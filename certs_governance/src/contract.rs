@@ -1,7 +1,7 @@
 //! Module Contract
 //!
 //! Module containing the main contract logic.
-use crate::certs_wallet::{self, OptionU64};
+use crate::certs_wallet::{self, OptionalTimestamp, RevocationReason};
 use crate::error::ContractError;
 use crate::governance_trait::GovernanceTrait;
 use crate::metadata::{
@@ -26,7 +26,7 @@ impl GovernanceTrait for CertGovernance {
         name: Bytes,
         receivers: Option<Vec<Address>>,
         distribution_limit: Option<u32>,
-        governance_rules: (bool, OptionU64), // (revocable, expiration_time)
+        governance_rules: (bool, OptionalTimestamp), // (revocable, expiration_time)
         organization: Organization,
     ) {
         if has_organization(&e) {
@@ -49,17 +49,31 @@ impl GovernanceTrait for CertGovernance {
         receiver: Address,
         wallet_contract_id: BytesN<32>,
         cid: Bytes,
+        content_hash: BytesN<32>,
         distribution_date: u64,
     ) {
         check_admin(&e, &admin);
         admin.require_auth();
         check_amount(&e);
 
-        apply_distribution(e, receiver, wallet_contract_id, cid, distribution_date);
+        apply_distribution(
+            e,
+            receiver,
+            wallet_contract_id,
+            cid,
+            content_hash,
+            distribution_date,
+        );
     }
 
-    /// Revoke a Chaincert from a holder.
-    fn revoke(e: Env, admin: Address, holder: Address, wallet_contract_id: BytesN<32>) {
+    /// Revoke a Chaincert from a holder, giving the reason of the revocation.
+    fn revoke(
+        e: Env,
+        admin: Address,
+        holder: Address,
+        wallet_contract_id: BytesN<32>,
+        reason: RevocationReason,
+    ) {
         check_revocable(&e);
         check_admin(&e, &admin);
         admin.require_auth();
@@ -67,7 +81,7 @@ impl GovernanceTrait for CertGovernance {
         let mut cert_data: CertData = receivers.get(holder.clone()).unwrap().unwrap();
         check_receiver_status_for_revoke(&e, &cert_data);
 
-        revoke_from_wallet(&e, wallet_contract_id, &cert_data.id);
+        revoke_from_wallet(&e, wallet_contract_id, &cert_data.id, reason);
         cert_data.status = Status::Revoked;
         receivers.set(holder, cert_data);
         write_receivers(&e, receivers);
@@ -84,7 +98,7 @@ impl GovernanceTrait for CertGovernance {
     }
 
     /// Get the Chaincert expiration time (Epoch time).
-    fn expiration_time(e: Env) -> OptionU64 {
+    fn expiration_time(e: Env) -> OptionalTimestamp {
         read_expiration_time(&e)
     }
 
@@ -126,15 +140,30 @@ fn apply_distribution(
     receiver: Address,
     wallet_contract_id: BytesN<32>,
     cid: Bytes,
+    content_hash: BytesN<32>,
     distribution_date: u64,
 ) {
     match read_receivers(&e).get(receiver.clone()) {
         Some(_) => {
-            distribute_receiver(&e, &receiver, distribution_date, wallet_contract_id, cid);
+            distribute_receiver(
+                &e,
+                &receiver,
+                distribution_date,
+                wallet_contract_id,
+                cid,
+                content_hash,
+            );
         }
         None => {
             add_receiver(&e, &receiver);
-            distribute_receiver(&e, &receiver, distribution_date, wallet_contract_id, cid);
+            distribute_receiver(
+                &e,
+                &receiver,
+                distribution_date,
+                wallet_contract_id,
+                cid,
+                content_hash,
+            );
         }
     };
 }
@@ -162,10 +191,10 @@ fn define_limit_and_receivers(
 }
 
 /// Calculates the expiration date of a distributed Chaincert (using Epoch Unix Timestamp, and Epoch time).
-fn expiration_date(e: &Env, distribution_date: u64) -> OptionU64 {
+fn expiration_date(e: &Env, distribution_date: u64) -> OptionalTimestamp {
     match read_expiration_time(e) {
-        OptionU64::Some(value) => OptionU64::Some(distribution_date + value),
-        OptionU64::None => OptionU64::None,
+        OptionalTimestamp::Some(value) => OptionalTimestamp::Some(distribution_date + value),
+        OptionalTimestamp::None => OptionalTimestamp::None,
     }
 }
 
@@ -203,6 +232,7 @@ fn distribute_receiver(
     distribution_date: u64,
     wallet_contract_id: BytesN<32>,
     cid: Bytes,
+    content_hash: BytesN<32>,
 ) {
     let mut receivers: Map<Address, CertData> = read_receivers(e);
     let mut cert_data: CertData = receivers.get(address.clone()).unwrap().unwrap();
@@ -213,11 +243,12 @@ fn distribute_receiver(
         wallet_contract_id,
         cert_data.id.clone(),
         cid,
+        content_hash,
         distribution_date,
     );
 
     cert_data.status = Status::Distribute;
-    cert_data.distribution_date = OptionU64::Some(distribution_date);
+    cert_data.distribution_date = OptionalTimestamp::Some(distribution_date);
     receivers.set(address.clone(), cert_data);
     write_receivers(e, receivers);
     increment_supply(e);
@@ -229,26 +260,36 @@ fn deposit_to_wallet(
     wallet_contract_id: BytesN<32>,
     chaincert_id: Bytes,
     cid: Bytes,
+    content_hash: BytesN<32>,
     distribution_date: u64,
 ) {
     let wallet_client = certs_wallet::Client::new(e, &wallet_contract_id);
     let distributor_contract = e.current_contract_address();
-    let expiration_date: OptionU64 = expiration_date(e, distribution_date);
+    let expiration_date: OptionalTimestamp = expiration_date(e, distribution_date);
     let org_id = read_organization_id(e);
     wallet_client.deposit_chaincert(
         &chaincert_id,
         &cid,
+        &content_hash,
         &distributor_contract,
         &org_id,
         &distribution_date,
         &expiration_date,
+        &OptionalTimestamp::None,
+        &Bytes::new(e),
+        &false,
     );
 }
 
 /// Invokes a wallet contract to execute a chaincert revocation.
-fn revoke_from_wallet(e: &Env, wallet_contract_id: BytesN<32>, chaincert_id: &Bytes) {
+fn revoke_from_wallet(
+    e: &Env,
+    wallet_contract_id: BytesN<32>,
+    chaincert_id: &Bytes,
+    reason: RevocationReason,
+) {
     let wallet_client = certs_wallet::Client::new(e, &wallet_contract_id);
     let distributor_contract = e.current_contract_address();
     let org_id = read_organization_id(e);
-    wallet_client.revoke_chaincert(chaincert_id, &distributor_contract, &org_id);
+    wallet_client.revoke_chaincert(chaincert_id, &distributor_contract, &org_id, &reason);
 }
@@ -4,7 +4,7 @@
 use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Vec};
 
 use crate::{
-    certs_wallet::OptionU64,
+    certs_wallet::{OptionalTimestamp, RevocationReason},
     storage_types::{CertData, Info, Organization},
 };
 pub trait GovernanceTrait {
@@ -15,7 +15,7 @@ pub trait GovernanceTrait {
         name: Bytes,
         receivers: Option<Vec<Address>>,
         distribution_limit: Option<u32>,
-        governance_rules: (bool, OptionU64),
+        governance_rules: (bool, OptionalTimestamp),
         organization: Organization,
     );
 
@@ -26,11 +26,18 @@ pub trait GovernanceTrait {
         receiver: Address,
         wallet_contract_id: BytesN<32>,
         cid: Bytes,
+        content_hash: BytesN<32>,
         distribution_date: u64,
     );
 
-    /// Revoke a Chaincert from a receiver.
-    fn revoke(e: Env, admin: Address, receiver: Address, wallet_contract_id: BytesN<32>);
+    /// Revoke a Chaincert from a receiver, giving the reason of the revocation.
+    fn revoke(
+        e: Env,
+        admin: Address,
+        receiver: Address,
+        wallet_contract_id: BytesN<32>,
+        reason: RevocationReason,
+    );
 
     /// Get the Chaincert name.
     fn name(e: Env) -> Bytes;
@@ -39,7 +46,7 @@ pub trait GovernanceTrait {
     fn is_revocable(e: Env) -> bool;
 
     /// Get the Chaincert expiration time (Unix time).
-    fn expiration_time(e: Env) -> OptionU64;
+    fn expiration_time(e: Env) -> OptionalTimestamp;
 
     /// Get the maximum number of Chaincerts that can be distributed by this contract.
     fn distribution_limit(e: Env) -> u32;
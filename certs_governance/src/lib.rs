@@ -6,6 +6,7 @@ mod metadata;
 mod organization;
 mod receivers;
 mod storage_types;
+#[allow(clippy::too_many_arguments)]
 mod certs_wallet {
     soroban_sdk::contractimport!(file = "./certs_wallet.wasm");
 }
@@ -2,7 +2,7 @@
 //!
 //! Module for obtaining and modifying the metadata fields.
 use crate::{
-    certs_wallet::OptionU64,
+    certs_wallet::OptionalTimestamp,
     storage_types::{CertData, DataKey},
 };
 use soroban_sdk::{Address, Bytes, Env, Map};
@@ -37,12 +37,12 @@ pub fn write_revocable(e: &Env, revocable: bool) {
     e.storage().set(&key, &revocable)
 }
 
-pub fn read_expiration_time(e: &Env) -> OptionU64 {
+pub fn read_expiration_time(e: &Env) -> OptionalTimestamp {
     let key = DataKey::ExpirationTime;
     e.storage().get_unchecked(&key).unwrap()
 }
 
-pub fn write_expiration_time(e: &Env, expiration_time: OptionU64) {
+pub fn write_expiration_time(e: &Env, expiration_time: OptionalTimestamp) {
     let key = DataKey::ExpirationTime;
     e.storage().set(&key, &expiration_time)
 }
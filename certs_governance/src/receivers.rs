@@ -5,7 +5,7 @@ use soroban_sdk::{map, Address, Bytes, Env, Map, Vec};
 use uuid::Uuid;
 
 use crate::{
-    certs_wallet::OptionU64,
+    certs_wallet::OptionalTimestamp,
     storage_types::{CertData, DataKey, Status},
 };
 
@@ -19,7 +19,7 @@ pub fn create_receivers(e: &Env, receivers_address: Vec<Address>) {
     receivers_address.iter().for_each(|receiver| {
         let address: Address = receiver.unwrap();
         let id_cert = create_unique_id(e, &address);
-        let chaincert_data = CertData::new(id_cert, Status::Unassigned, OptionU64::None);
+        let chaincert_data = CertData::new(id_cert, Status::Unassigned, OptionalTimestamp::None);
         receivers.set(address, chaincert_data);
     });
     let key = DataKey::Receivers;
@@ -29,7 +29,7 @@ pub fn create_receivers(e: &Env, receivers_address: Vec<Address>) {
 pub fn add_receiver(e: &Env, address: &Address) {
     let mut receivers = read_receivers(e);
     let id_cert = create_unique_id(e, address);
-    let cert_data = CertData::new(id_cert, Status::Unassigned, OptionU64::None);
+    let cert_data = CertData::new(id_cert, Status::Unassigned, OptionalTimestamp::None);
     receivers.set(address.clone(), cert_data);
     let key = DataKey::Receivers;
     e.storage().set(&key, &receivers);
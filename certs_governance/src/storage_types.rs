@@ -1,7 +1,7 @@
 //! Module Storage Types
 //!
 //! Module where the DataKey of the contract and some necessary structs are defined.
-use crate::certs_wallet::OptionU64;
+use crate::certs_wallet::OptionalTimestamp;
 use soroban_sdk::{contracttype, Address, Bytes};
 
 #[contracttype]
@@ -24,11 +24,11 @@ pub enum Status {
 pub struct CertData {
     pub id: Bytes,
     pub status: Status,
-    pub distribution_date: OptionU64,
+    pub distribution_date: OptionalTimestamp,
 }
 
 impl CertData {
-    pub fn new(id: Bytes, status: Status, distribution_date: OptionU64) -> CertData {
+    pub fn new(id: Bytes, status: Status, distribution_date: OptionalTimestamp) -> CertData {
         CertData {
             id,
             status,
@@ -42,7 +42,7 @@ impl CertData {
 pub struct Info {
     pub name: Bytes,
     pub revocable: bool,
-    pub expiration_time: OptionU64,
+    pub expiration_time: OptionalTimestamp,
     pub distribution_limit: u32,
     pub supply: u32,
 }
@@ -53,7 +53,7 @@ pub enum DataKey {
     FileStorage,       // Bytes
     Name,              // Bytes
     Revocable,         // bool
-    ExpirationTime,    // OptionU64
+    ExpirationTime,    // OptionalTimestamp
     Receivers,         // Map <Address, CertData>
     Organization,      // Organization
     DistributionLimit, // u32
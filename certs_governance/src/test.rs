@@ -1,16 +1,17 @@
 #![cfg(test)]
-use crate::certs_wallet::{self, OptionU64};
+use crate::certs_wallet::{self, OptionalTimestamp, RevocationReason};
 use crate::storage_types::{CertData, Info, Organization, Status};
 use crate::{contract::CertGovernance, CertGovernanceClient};
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{vec, Address, Bytes, Env, IntoVal, Vec};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Vec};
 
+const CONTENT_HASH: [u8; 32] = [1; 32];
 const WASM: &[u8] = include_bytes!("../../target/wasm32-unknown-unknown/release/certs_wallet.wasm");
 
 fn create_wallet_contract(env: &Env, owner: &Address, id: &Bytes) -> certs_wallet::Client {
     let wallet = certs_wallet::Client::new(env, &env.register_contract_wasm(None, WASM));
-    wallet.initialize(owner);
-    wallet.add_organization(id);
+    wallet.initialize(owner, &Bytes::new(env), &Bytes::new(env));
+    wallet.add_organization(id, &OptionalTimestamp::None);
     wallet
 }
 
@@ -19,7 +20,7 @@ fn create_cert_governance_contract_with_limit(
     limit: &Option<u32>,
     address_receivers: &Option<Vec<Address>>,
     organization: &Organization,
-    governance_rules: &(bool, OptionU64),
+    governance_rules: &(bool, OptionalTimestamp),
 ) -> CertGovernanceClient {
     let cert_governance =
         CertGovernanceClient::new(e, &e.register_contract(None, CertGovernance {}));
@@ -39,7 +40,7 @@ fn create_cert_governance_contract_with_receivers(
     limit: &Option<u32>,
     address_receivers: &Option<Vec<Address>>,
     organization: &Organization,
-    governance_rules: &(bool, OptionU64),
+    governance_rules: &(bool, OptionalTimestamp),
 ) -> CertGovernanceClient {
     let cert_governance =
         CertGovernanceClient::new(e, &e.register_contract(None, CertGovernance {}));
@@ -67,7 +68,7 @@ fn test_create_cert_data() {
     let e: Env = Default::default();
     let id_chaincert: Bytes = "12345".into_val(&e);
     let status = Status::Unassigned;
-    let distribution_date = OptionU64::Some(1711195200);
+    let distribution_date = OptionalTimestamp::Some(1711195200);
     let cert_data = CertData::new(
         id_chaincert.clone(),
         status.clone(),
@@ -86,7 +87,7 @@ fn test_initialize_contract_with_receivers() {
         admin: Address::random(&e),
         id: "12345".into_val(&e),
     };
-    let governance_rules = (true, OptionU64::Some(1680091200));
+    let governance_rules = (true, OptionalTimestamp::Some(1680091200));
 
     let cert_governance: CertGovernanceClient = create_cert_governance_contract_with_receivers(
         &e,
@@ -100,7 +101,7 @@ fn test_initialize_contract_with_receivers() {
     assert!(cert_governance.is_revocable());
     assert_eq!(
         cert_governance.expiration_time(),
-        OptionU64::Some(1680091200)
+        OptionalTimestamp::Some(1680091200)
     );
     assert_eq!(cert_governance.distribution_limit(), 3);
     assert_eq!(cert_governance.supply(), 0);
@@ -115,7 +116,7 @@ fn test_initialize_with_limit_contract() {
         id: "12345".into_val(&e),
     };
     let distribution_limit: Option<u32> = Option::Some(6);
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let cert_governance = create_cert_governance_contract_with_limit(
         &e,
@@ -127,7 +128,7 @@ fn test_initialize_with_limit_contract() {
     assert_eq!(cert_governance.file_storage(), "FileBase".into_val(&e));
     assert_eq!(cert_governance.name(), "ChaincertName".into_val(&e));
     assert!(cert_governance.is_revocable());
-    assert_eq!(cert_governance.expiration_time(), OptionU64::None);
+    assert_eq!(cert_governance.expiration_time(), OptionalTimestamp::None);
     assert_eq!(cert_governance.distribution_limit(), 6);
     assert_eq!(cert_governance.supply(), 0);
     assert_eq!(cert_governance.receivers().len(), 0);
@@ -141,7 +142,7 @@ fn test_initialize_without_limit_contract_and_receivers() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let cert_governance = create_cert_governance_contract_with_limit(
         &e,
@@ -153,7 +154,7 @@ fn test_initialize_without_limit_contract_and_receivers() {
     assert_eq!(cert_governance.file_storage(), "FileBase".into_val(&e));
     assert_eq!(cert_governance.name(), "ChaincertName".into_val(&e));
     assert!(cert_governance.is_revocable());
-    assert_eq!(cert_governance.expiration_time(), OptionU64::None);
+    assert_eq!(cert_governance.expiration_time(), OptionalTimestamp::None);
     assert_eq!(cert_governance.distribution_limit(), 10);
     assert_eq!(cert_governance.supply(), 0);
     assert_eq!(cert_governance.receivers().len(), 0);
@@ -166,9 +167,9 @@ fn test_get_contract_info() {
         admin: Address::random(&e),
         id: "12345".into_val(&e),
     };
-    let governance_rules_without_expiration_time = (true, OptionU64::None);
+    let governance_rules_without_expiration_time = (true, OptionalTimestamp::None);
 
-    let governance_rules_with_expiration_time = (true, OptionU64::Some(31556926));
+    let governance_rules_with_expiration_time = (true, OptionalTimestamp::Some(31556926));
 
     let distribution_limit: Option<u32> = Option::Some(6);
 
@@ -191,7 +192,7 @@ fn test_get_contract_info() {
     let info = Info {
         name: "ChaincertName".into_val(&e),
         revocable: true,
-        expiration_time: OptionU64::None,
+        expiration_time: OptionalTimestamp::None,
         distribution_limit: 6,
         supply: 0,
     };
@@ -199,7 +200,7 @@ fn test_get_contract_info() {
     let info_2 = Info {
         name: "ChaincertName".into_val(&e),
         revocable: true,
-        expiration_time: OptionU64::Some(31556926),
+        expiration_time: OptionalTimestamp::Some(31556926),
         distribution_limit: 6,
         supply: 0,
     };
@@ -218,7 +219,7 @@ fn test_distribute_with_distribution_limit_contract() {
         id: "12345".into_val(&e),
     };
     let distribution_limit: Option<u32> = Option::Some(6);
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
     let cert_governance = create_cert_governance_contract_with_limit(
         &e,
         &distribution_limit,
@@ -235,6 +236,7 @@ fn test_distribute_with_distribution_limit_contract() {
         &address_receiver_1,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
     let receivers = cert_governance.receivers();
@@ -263,7 +265,7 @@ fn test_distribute_with_initial_receivers() {
     };
     let distribution_date: u64 = 1679918400;
     pub const CID1: &str = "QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMrEC";
-    let governance_rules = (true, OptionU64::Some(31556926));
+    let governance_rules = (true, OptionalTimestamp::Some(31556926));
     let cert_governance = create_cert_governance_contract_with_receivers(
         &e,
         &Option::None,
@@ -281,6 +283,7 @@ fn test_distribute_with_initial_receivers() {
         &address_receiver_1,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
@@ -309,7 +312,7 @@ fn test_revoke_chaincert() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     pub const CID1: &str = "QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMrEC";
@@ -327,6 +330,7 @@ fn test_revoke_chaincert() {
         &receiver_address,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
@@ -334,14 +338,28 @@ fn test_revoke_chaincert() {
     let mut cert_data = receivers.get(receiver_address.clone()).unwrap().unwrap();
     assert_eq!(cert_data.status, Status::Distribute);
 
-    cert_governance.revoke(&organization.admin, &receiver_address, &wallet.contract_id);
+    cert_governance.revoke(
+        &organization.admin,
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
 
     receivers = cert_governance.receivers();
     cert_data = receivers.get(receiver_address).unwrap().unwrap();
     assert_eq!(cert_data.status, Status::Revoked);
 
     let chaincert = wallet.get_chaincerts().get(0).unwrap().unwrap();
+    assert_eq!(
+        chaincert.content_hash,
+        BytesN::from_array(&e, &CONTENT_HASH)
+    );
     assert!(chaincert.revoked);
+    assert_eq!(chaincert.revocation_reason, RevocationReason::IssuedInError);
+    assert_eq!(
+        chaincert.revocation_date,
+        OptionalTimestamp::Some(e.ledger().timestamp())
+    );
 }
 
 #[test]
@@ -354,7 +372,7 @@ fn test_initialize_contract_with_receivers_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let cert_governance: CertGovernanceClient = create_cert_governance_contract_with_receivers(
         &e,
@@ -383,7 +401,7 @@ fn test_initialize_with_limit_contract_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_limit: Option<u32> = Option::Some(6);
     let cert_governance = create_cert_governance_contract_with_limit(
@@ -423,7 +441,7 @@ fn test_distribute_admin_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     let cert_governance = create_cert_governance_contract_with_receivers(
@@ -439,6 +457,7 @@ fn test_distribute_admin_error() {
         &receiver_address,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 }
@@ -474,7 +493,7 @@ fn test_distribute_limit_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     let distribution_limit = Option::Some(1);
@@ -498,6 +517,7 @@ fn test_distribute_limit_error() {
             .unwrap(),
         &wallet1.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
@@ -510,6 +530,7 @@ fn test_distribute_limit_error() {
             .unwrap(),
         &wallet2.contract_id,
         &CID2.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 }
@@ -534,7 +555,7 @@ fn test_distribute_status_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     let distribution_limit = Option::Some(3);
@@ -557,6 +578,7 @@ fn test_distribute_status_error() {
             .unwrap(),
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
@@ -569,6 +591,7 @@ fn test_distribute_status_error() {
             .unwrap(),
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 }
@@ -590,7 +613,7 @@ fn test_revoke_admin_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     pub const CID1: &str = "QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMrEC";
@@ -607,10 +630,16 @@ fn test_revoke_admin_error() {
         &receiver_address,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
-    cert_governance.revoke(&Address::random(&e), &receiver_address, &wallet.contract_id);
+    cert_governance.revoke(
+        &Address::random(&e),
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
 }
 
 #[test]
@@ -630,7 +659,7 @@ fn test_revoke_status_unassigned_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let cert_governance = create_cert_governance_contract_with_receivers(
         &e,
@@ -640,7 +669,12 @@ fn test_revoke_status_unassigned_error() {
         &governance_rules,
     );
 
-    cert_governance.revoke(&organization.admin, &receiver_address, &wallet.contract_id);
+    cert_governance.revoke(
+        &organization.admin,
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
 }
 
 #[test]
@@ -660,7 +694,7 @@ fn test_revoke_status_revoked_error() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (true, OptionU64::None);
+    let governance_rules = (true, OptionalTimestamp::None);
 
     let distribution_date: u64 = 1679918400;
     pub const CID1: &str = "QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMrEC";
@@ -676,11 +710,22 @@ fn test_revoke_status_revoked_error() {
         &receiver_address,
         &wallet.contract_id,
         &CID1.into_val(&e),
+        &BytesN::from_array(&e, &CONTENT_HASH),
         &distribution_date,
     );
 
-    cert_governance.revoke(&organization.admin, &receiver_address, &wallet.contract_id);
-    cert_governance.revoke(&organization.admin, &receiver_address, &wallet.contract_id);
+    cert_governance.revoke(
+        &organization.admin,
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
+    cert_governance.revoke(
+        &organization.admin,
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
 }
 
 #[test]
@@ -700,7 +745,7 @@ fn test_revoke_no_revocable_cert() {
         id: "12345".into_val(&e),
     };
 
-    let governance_rules = (false, OptionU64::None);
+    let governance_rules = (false, OptionalTimestamp::None);
 
     let cert_governance = create_cert_governance_contract_with_receivers(
         &e,
@@ -716,5 +761,10 @@ fn test_revoke_no_revocable_cert() {
         .unwrap()
         .unwrap();
 
-    cert_governance.revoke(&organization.admin, &receiver_address, &wallet.contract_id);
+    cert_governance.revoke(
+        &organization.admin,
+        &receiver_address,
+        &wallet.contract_id,
+        &RevocationReason::IssuedInError,
+    );
 }
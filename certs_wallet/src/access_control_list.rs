@@ -1,87 +1,290 @@
 //! Module AccessControlList
 //!
 //! Module responsible of managing the ACL that allows organizations to deposit `Chaincerts` to a wallet
-use soroban_sdk::{panic_with_error, vec, Bytes, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Bytes, Env, Vec};
 
-use crate::error::ContractError;
+use crate::{
+    error::ContractError,
+    option::{OptionalAddress, OptionalTimestamp},
+};
 
 use super::storage_types::DataKey;
 
-const ACL_KEY: DataKey = DataKey::AccessControlList;
+const ORG_IDS_KEY: DataKey = DataKey::OrganizationIds;
+const ACL_ADMIN_KEY: DataKey = DataKey::AclAdmin;
 
-pub(crate) fn get_access_control_list(env: &Env) -> Vec<Bytes> {
-    match env.storage().get(&ACL_KEY) {
-        Some(acl) => acl.unwrap(),
-        None => {
-            panic_with_error!(env, ContractError::NoOrganizationsInACL)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The actions an organization can perform over the `Chaincerts` of the wallet
+pub enum Permission {
+    Deposit,
+    Revoke,
+    Update,
+    /// Endorse the `Chaincerts` distributed by other organizations
+    Endorse,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Descriptive information of an organization, empty fields are not set
+pub struct OrgInfo {
+    /// The display name of the organization
+    pub name: Bytes,
+    /// The DID of the organization
+    pub did: Bytes,
+    /// The contact service endpoint of the organization
+    pub service_endpoint: Bytes,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// An organization authorized in the ACL along with its permissions
+pub struct OrgEntry {
+    pub org_id: Bytes,
+    pub permissions: Vec<Permission>,
+    /// The date in Unix Timestamp format after which the organization is no longer authorized
+    pub expiration_date: OptionalTimestamp,
+    pub info: OrgInfo,
+    /// A logical indicator that lets know if the organization is temporarily not authorized
+    pub suspended: bool,
+    /// The address that signs the endorsements on behalf of the organization, `None` when it can't endorse
+    pub endorser: OptionalAddress,
+}
+
+impl OrgEntry {
+    pub(crate) fn new(env: &Env, org_id: Bytes, expiration_date: OptionalTimestamp) -> OrgEntry {
+        OrgEntry {
+            org_id,
+            permissions: vec![
+                env,
+                Permission::Deposit,
+                Permission::Revoke,
+                Permission::Update,
+                Permission::Endorse,
+            ],
+            expiration_date,
+            info: OrgInfo {
+                name: Bytes::new(env),
+                did: Bytes::new(env),
+                service_endpoint: Bytes::new(env),
+            },
+            suspended: false,
+            endorser: OptionalAddress::None,
         }
     }
+
+    fn is_expired(&self, env: &Env) -> bool {
+        self.expiration_date.is_expired(env)
+    }
 }
 
-pub(crate) fn add_organization(env: &Env, org_id: &Bytes) {
-    let acl = match env.storage().get(&ACL_KEY) {
-        Some(acl) => {
-            let mut access_list: Vec<Bytes> = acl.unwrap();
-            if !is_organization_in_access_control_list(org_id, &access_list) {
-                access_list.push_front(org_id.clone());
-                access_list
-            } else {
-                panic_with_error!(env, ContractError::AlreadyInACL)
-            }
-        }
-        None => {
-            let access_list: Vec<Bytes> = vec![env, org_id.clone()];
-            access_list
+pub(crate) fn get_access_control_list(env: &Env) -> Vec<OrgEntry> {
+    read_organizations(env, &read_organization_ids(env))
+}
+
+/// Returns the organizations of the ACL, empty when no organization was added
+pub(crate) fn read_entries(env: &Env) -> Vec<OrgEntry> {
+    match env.storage().get(&ORG_IDS_KEY) {
+        Some(org_ids) => read_organizations(env, &org_ids.unwrap()),
+        None => Vec::new(env),
+    }
+}
+
+pub(crate) fn acl_count(env: &Env) -> u32 {
+    match env.storage().get(&ORG_IDS_KEY) {
+        Some(org_ids) => {
+            let org_ids: Vec<Bytes> = org_ids.unwrap();
+            org_ids.len()
         }
+        None => 0,
+    }
+}
+
+pub(crate) fn add_organization(env: &Env, org_id: &Bytes, expiration_date: &OptionalTimestamp) {
+    let mut org_ids: Vec<Bytes> = match env.storage().get(&ORG_IDS_KEY) {
+        Some(org_ids) => org_ids.unwrap(),
+        None => Vec::new(env),
     };
-    env.storage().set(&ACL_KEY, &acl)
+    insert_organization(env, &mut org_ids, org_id, expiration_date);
+    write_organization_ids(env, &org_ids)
 }
 
 pub(crate) fn remove_organization(env: &Env, org_id: &Bytes) {
-    match env.storage().get(&ACL_KEY) {
-        Some(acl) => {
-            let mut access_list: Vec<Bytes> = acl.unwrap();
-            remove_from_access_control_list(env, org_id, &mut access_list);
-            env.storage().set(&ACL_KEY, &access_list)
-        }
-        None => {
-            panic_with_error!(env, ContractError::NoOrganizationsInACL)
+    let mut org_ids = read_organization_ids(env);
+    remove_from_access_control_list(env, org_id, &mut org_ids);
+    write_organization_ids(env, &org_ids)
+}
+
+pub(crate) fn add_organizations(
+    env: &Env,
+    org_ids: &Vec<Bytes>,
+    expiration_date: &OptionalTimestamp,
+) {
+    let mut stored_ids: Vec<Bytes> = match env.storage().get(&ORG_IDS_KEY) {
+        Some(org_ids) => org_ids.unwrap(),
+        None => Vec::new(env),
+    };
+    for org_id in org_ids.iter() {
+        insert_organization(env, &mut stored_ids, &org_id.unwrap(), expiration_date);
+    }
+    write_organization_ids(env, &stored_ids)
+}
+
+pub(crate) fn remove_organizations(env: &Env, org_ids: &Vec<Bytes>) {
+    let mut stored_ids = read_organization_ids(env);
+    for org_id in org_ids.iter() {
+        remove_from_access_control_list(env, &org_id.unwrap(), &mut stored_ids);
+    }
+    write_organization_ids(env, &stored_ids)
+}
+
+pub(crate) fn set_organization_permissions(
+    env: &Env,
+    org_id: &Bytes,
+    permissions: &Vec<Permission>,
+) {
+    let mut org = get_organization(env, org_id);
+    org.permissions = permissions.clone();
+    write_organization(env, &org)
+}
+
+pub(crate) fn set_organization_info(env: &Env, org_id: &Bytes, info: &OrgInfo) {
+    let mut org = get_organization(env, org_id);
+    org.info = info.clone();
+    write_organization(env, &org)
+}
+
+pub(crate) fn set_organization_suspended(env: &Env, org_id: &Bytes, suspended: bool) {
+    let mut org = get_organization(env, org_id);
+    org.suspended = suspended;
+    write_organization(env, &org)
+}
+
+pub(crate) fn set_organization_endorser(env: &Env, org_id: &Bytes, endorser: &Address) {
+    let mut org = get_organization(env, org_id);
+    org.endorser = OptionalAddress::Some(endorser.clone());
+    write_organization(env, &org)
+}
+
+/// Checks that the endorser signs the endorsements of the organization, requiring its authorization
+pub(crate) fn check_endorser(env: &Env, org_id: &Bytes, endorser: &Address) {
+    let org = read_organization(env, org_id, ContractError::NotAuthorized);
+    if org.endorser != OptionalAddress::Some(endorser.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized)
+    }
+    endorser.require_auth();
+}
+
+pub(crate) fn get_organization(env: &Env, org_id: &Bytes) -> OrgEntry {
+    read_organization(env, org_id, ContractError::OrganizationNotFound)
+}
+
+pub(crate) fn is_organization_authorized(env: &Env, org_id: &Bytes) -> bool {
+    match env.storage().get(&DataKey::Organization(org_id.clone())) {
+        Some(org) => {
+            let org: OrgEntry = org.unwrap();
+            org.permissions.contains(Permission::Deposit) && !org.is_expired(env) && !org.suspended
         }
+        None => false,
+    }
+}
+
+pub(crate) fn check_access_control_list(env: &Env, org_id: &Bytes, permission: Permission) {
+    let org = read_organization(env, org_id, ContractError::NotAuthorized);
+    if org.suspended {
+        panic_with_error!(env, ContractError::OrganizationSuspended)
+    }
+    if !org.permissions.contains(permission) || org.is_expired(env) {
+        panic_with_error!(env, ContractError::NotAuthorized)
     }
 }
 
-pub(crate) fn check_access_control_list(env: &Env, org_id: &Bytes) {
-    match env.storage().get(&ACL_KEY) {
-        Some(acl) => {
-            let access_list: Vec<Bytes> = acl.unwrap();
-            for org in access_list.iter() {
-                if org.unwrap() == org_id.clone() {
-                    return;
-                }
+pub(crate) fn write_acl_admin(env: &Env, admin: &Address) {
+    env.storage().set(&ACL_ADMIN_KEY, admin)
+}
+
+pub(crate) fn remove_acl_admin(env: &Env) {
+    env.storage().remove(&ACL_ADMIN_KEY)
+}
+
+pub(crate) fn read_acl_admin(env: &Env) -> Address {
+    match env.storage().get(&ACL_ADMIN_KEY) {
+        Some(admin) => admin.unwrap(),
+        None => panic_with_error!(env, ContractError::NoAclAdmin),
+    }
+}
+
+/// Checks that the admin is the one the ACL administration is delegated to, requiring its authorization
+pub(crate) fn check_acl_admin(env: &Env, admin: &Address) {
+    match env.storage().get(&ACL_ADMIN_KEY) {
+        Some(acl_admin) => {
+            let acl_admin: Address = acl_admin.unwrap();
+            if acl_admin != admin.clone() {
+                panic_with_error!(env, ContractError::NotAuthorized)
             }
-            panic_with_error!(env, ContractError::NotAuthorized)
+            admin.require_auth();
         }
+        None => panic_with_error!(env, ContractError::NotAuthorized),
+    }
+}
+
+fn insert_organization(
+    env: &Env,
+    org_ids: &mut Vec<Bytes>,
+    org_id: &Bytes,
+    expiration_date: &OptionalTimestamp,
+) {
+    match org_ids.binary_search(org_id) {
+        Ok(_) => panic_with_error!(env, ContractError::AlreadyInACL),
+        Err(index) => org_ids.insert(index, org_id.clone()),
+    }
+    write_organization(
+        env,
+        &OrgEntry::new(env, org_id.clone(), expiration_date.clone()),
+    )
+}
+
+fn remove_from_access_control_list(env: &Env, org_id: &Bytes, org_ids: &mut Vec<Bytes>) {
+    match org_ids.binary_search(org_id) {
+        Ok(index) => org_ids.remove(index),
+        Err(_) => panic_with_error!(env, ContractError::OrganizationNotFound),
+    };
+    env.storage().remove(&DataKey::Organization(org_id.clone()))
+}
+
+/// Reads an organization from its own storage entry, failing with the given error when it is not in the ACL
+fn read_organization(env: &Env, org_id: &Bytes, not_found: ContractError) -> OrgEntry {
+    match env.storage().get(&DataKey::Organization(org_id.clone())) {
+        Some(org) => org.unwrap(),
         None => {
-            panic_with_error!(env, ContractError::NoOrganizationsInACL)
+            if !env.storage().has(&ORG_IDS_KEY) {
+                panic_with_error!(env, ContractError::NoOrganizationsInACL)
+            }
+            panic_with_error!(env, not_found)
         }
     }
 }
 
-fn remove_from_access_control_list(env: &Env, org_id: &Bytes, access_list: &mut Vec<Bytes>) {
-    for (index, org) in access_list.iter().enumerate() {
-        if org.unwrap() == org_id.clone() {
-            access_list.remove(index as u32).unwrap();
-            return;
-        }
+pub(crate) fn write_organization(env: &Env, org: &OrgEntry) {
+    env.storage()
+        .set(&DataKey::Organization(org.org_id.clone()), org)
+}
+
+fn read_organizations(env: &Env, org_ids: &Vec<Bytes>) -> Vec<OrgEntry> {
+    let mut orgs: Vec<OrgEntry> = Vec::new(env);
+    for org_id in org_ids.iter() {
+        orgs.push_back(get_organization(env, &org_id.unwrap()));
     }
-    panic_with_error!(env, ContractError::OrganizationNotFound)
+    orgs
 }
 
-fn is_organization_in_access_control_list(org_id: &Bytes, access_list: &Vec<Bytes>) -> bool {
-    for org in access_list.iter() {
-        if org.unwrap() == org_id.clone() {
-            return true;
-        }
+fn read_organization_ids(env: &Env) -> Vec<Bytes> {
+    match env.storage().get(&ORG_IDS_KEY) {
+        Some(org_ids) => org_ids.unwrap(),
+        None => panic_with_error!(env, ContractError::NoOrganizationsInACL),
     }
-    false
+}
+
+pub(crate) fn write_organization_ids(env: &Env, org_ids: &Vec<Bytes>) {
+    env.storage().set(&ORG_IDS_KEY, org_ids)
 }
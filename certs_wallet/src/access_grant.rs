@@ -0,0 +1,87 @@
+//! Module AccessGrant
+//!
+//! Module responsible of managing the time-limited read access granted by the owner to verifiers
+//! over specific `Chaincerts`.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Vec};
+
+use crate::{
+    audit::{self, AuditAction},
+    chaincert::{self, Chaincert},
+    error::ContractError,
+    storage_types::DataKey,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The `Chaincerts` shared with a verifier and the date until which they are shared
+pub struct AccessGrant {
+    pub chaincert_ids: Vec<Bytes>,
+    /// The date in Unix Timestamp format after which the verifier can no longer read the `Chaincerts`
+    pub expiration_date: u64,
+}
+
+pub(crate) fn grant_access(
+    env: &Env,
+    verifier: &Address,
+    chaincert_ids: &Vec<Bytes>,
+    expiration_date: u64,
+) {
+    for chaincert_id in chaincert_ids.iter() {
+        chaincert::get_chaincert(env, &chaincert_id.unwrap());
+    }
+    let grant = AccessGrant {
+        chaincert_ids: chaincert_ids.clone(),
+        expiration_date,
+    };
+    env.storage()
+        .set(&DataKey::AccessGrant(verifier.clone()), &grant)
+}
+
+pub(crate) fn revoke_access(env: &Env, verifier: &Address) {
+    env.storage()
+        .remove(&DataKey::AccessGrant(verifier.clone()))
+}
+
+/// Returns the `Chaincerts` shared with the verifier that are still stored in the wallet, recording the access
+pub(crate) fn get_shared_chaincerts(env: &Env, verifier: &Address) -> Vec<Chaincert> {
+    let grant = read_access_grant(env, verifier);
+    audit::record(
+        env,
+        verifier,
+        AuditAction::AccessGrantUsed,
+        &grant.chaincert_ids,
+    );
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert_id in grant.chaincert_ids.iter() {
+        if let Some(chaincert) = chaincert::find_chaincert(env, &chaincert_id.unwrap()) {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+/// Checks the `Chaincert` is shared with the verifier through a grant that is not expired
+pub(crate) fn is_shared_with(env: &Env, verifier: &Address, chaincert_id: &Bytes) -> bool {
+    match env.storage().get(&DataKey::AccessGrant(verifier.clone())) {
+        Some(grant) => {
+            let grant: AccessGrant = grant.unwrap();
+            grant.expiration_date > env.ledger().timestamp()
+                && grant.chaincert_ids.contains(chaincert_id)
+        }
+        None => false,
+    }
+}
+
+/// Reads the grant of the verifier, which must not be expired
+fn read_access_grant(env: &Env, verifier: &Address) -> AccessGrant {
+    match env.storage().get(&DataKey::AccessGrant(verifier.clone())) {
+        Some(grant) => {
+            let grant: AccessGrant = grant.unwrap();
+            if grant.expiration_date <= env.ledger().timestamp() {
+                panic_with_error!(env, ContractError::NotAuthorized)
+            }
+            grant
+        }
+        None => panic_with_error!(env, ContractError::NotAuthorized),
+    }
+}
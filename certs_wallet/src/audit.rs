@@ -0,0 +1,80 @@
+//! Module Audit
+//!
+//! Module responsible of keeping a bounded record of the verifiers that consumed the `Chaincerts` of
+//! the wallet, either through an access grant or a presentation, for compliance purposes.
+use soroban_sdk::{contracttype, Address, Bytes, Env, Vec};
+
+use crate::storage_types::DataKey;
+
+const AUDIT_LOG_KEY: DataKey = DataKey::AuditLog;
+
+/// Maximum number of entries kept in the audit log, the oldest entries are discarded first
+const MAX_AUDIT_ENTRIES: u32 = 100;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The ways a verifier can consume the `Chaincerts` of the wallet
+pub enum AuditAction {
+    /// The verifier read the `Chaincerts` shared through an access grant
+    AccessGrantUsed,
+    /// The verifier verified a presentation made by the owner
+    PresentationVerified,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A record of a verifier consuming `Chaincerts` of the wallet
+pub struct AuditEntry {
+    pub verifier: Address,
+    pub action: AuditAction,
+    pub chaincert_ids: Vec<Bytes>,
+    /// The ledger timestamp of the access in Unix Timestamp format
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A page of the audit log along with the total number of entries kept
+pub struct AuditPage {
+    /// The entries of the requested page, from the oldest to the most recent
+    pub entries: Vec<AuditEntry>,
+    pub total: u32,
+}
+
+pub(crate) fn record(
+    env: &Env,
+    verifier: &Address,
+    action: AuditAction,
+    chaincert_ids: &Vec<Bytes>,
+) {
+    let mut audit_log = read_audit_log(env);
+    if audit_log.len() >= MAX_AUDIT_ENTRIES {
+        audit_log.pop_front();
+    }
+    audit_log.push_back(AuditEntry {
+        verifier: verifier.clone(),
+        action,
+        chaincert_ids: chaincert_ids.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().set(&AUDIT_LOG_KEY, &audit_log)
+}
+
+pub(crate) fn get_audit_log(env: &Env, offset: u32, limit: u32) -> AuditPage {
+    let audit_log = read_audit_log(env);
+    let total = audit_log.len();
+    let start = offset.min(total);
+    let end = start.saturating_add(limit).min(total);
+
+    AuditPage {
+        entries: audit_log.slice(start..end),
+        total,
+    }
+}
+
+fn read_audit_log(env: &Env) -> Vec<AuditEntry> {
+    match env.storage().get(&AUDIT_LOG_KEY) {
+        Some(audit_log) => audit_log.unwrap(),
+        None => Vec::new(env),
+    }
+}
@@ -1,16 +1,40 @@
 //! Module Chaincert
 //!
 //! Module responsible of managing `Chaincerts` information and defining its corresponding struct.
-use crate::{error::ContractError, option::OptionU64, storage_types::DataKey};
-use soroban_sdk::{contracttype, map, panic_with_error, Address, Bytes, Env, Map, Vec};
+use crate::{
+    access_grant, endorsement,
+    error::ContractError,
+    history::{self, HistoryAction, HistoryEntry},
+    option::OptionalTimestamp,
+    owner,
+    state::{self, WalletState},
+    storage_types::DataKey,
+};
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, Map, Vec};
 
-const CHAINCERT_KEY: DataKey = DataKey::Chaincerts;
+const CHAINCERT_IDS_KEY: DataKey = DataKey::ChaincertIds;
+const CAPACITY_KEY: DataKey = DataKey::Capacity;
+const GRACE_PERIOD_KEY: DataKey = DataKey::GracePeriod;
+/// Seconds a revoked `Chaincert` must be expired before anyone can sweep it
+const SWEEP_DELAY: u64 = 2592000;
+const MAX_ATTRIBUTES: u32 = 10;
+const MAX_NOTE_LENGTH: u32 = 256;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 #[contracttype]
 /// The `Chaincert` information stored in the wallet
 pub struct Chaincert {
     pub cid: Bytes,
+    /// The sha256 hash of the document committed by the organization, all zeros for legacy `Chaincerts`
+    pub content_hash: BytesN<32>,
+    /// The cids replaced by the organization, from the oldest to the most recent
+    pub previous_cids: Vec<Bytes>,
+    /// The cids of additional documents of the `Chaincert`, e.g. a transcript or a verifiable credential
+    pub attachments: Vec<Bytes>,
+    /// Simple attributes set by the organization, e.g. grade, program code or locale
+    pub attributes: Map<Bytes, Bytes>,
+    /// Personal labels set by the owner, e.g. work or education
+    pub tags: Vec<Bytes>,
     /// Address of the governance contract that distributed the `Chaincert`
     pub distributor_contract: Address,
     /// The id of the organization that distributed the `Chaincert`
@@ -18,65 +42,216 @@ pub struct Chaincert {
     /// The distribution date in Unix Timestamp format
     pub distribution_date: u64,
     /// The expiration date in Unix Timestamp format
-    pub expiration_date: OptionU64,
+    pub expiration_date: OptionalTimestamp,
+    /// Whether the expiration date is reached, computed against the ledger timestamp when the `Chaincert` is read
+    pub expired: bool,
+    /// The date in Unix Timestamp format from which the `Chaincert` is valid
+    pub valid_from: OptionalTimestamp,
+    /// The category of the `Chaincert`, e.g. diploma, membership or license, empty if not set
+    pub cert_type: Bytes,
+    /// Whether the owner can transfer the `Chaincert` to another wallet, for bearer-style credentials
+    pub transferable: bool,
     /// A logical indicator that lets know if a `Chaincert` is revoked or not
     pub revoked: bool,
+    /// A logical indicator that lets know if the owner withdrew the `Chaincert`
+    pub withdrawn: bool,
+    /// Whether the `Chaincert` is listed by the public getters of the wallet
+    pub visibility: Visibility,
+    /// The reason given by the organization when revoking the `Chaincert`
+    pub revocation_reason: RevocationReason,
+    /// The revocation date in Unix Timestamp format
+    pub revocation_date: OptionalTimestamp,
+    /// A logical indicator that lets know if the owner contests the revocation
+    pub disputed: bool,
+    /// The reason given by the owner when disputing the revocation, empty if not disputed
+    pub dispute_reason: Bytes,
+    /// The date in Unix Timestamp format the organization reinstated the revoked `Chaincert`
+    pub reinstatement_date: OptionalTimestamp,
+    /// A logical indicator that lets know if the `Chaincert` was imported from a wallet snapshot and
+    /// is not attested again by its issuer
+    pub restored: bool,
 }
 
 impl Chaincert {
-    fn new(
+    pub(crate) fn new(
+        env: &Env,
         cid: Bytes,
+        content_hash: BytesN<32>,
         distributor_contract: Address,
         org_id: Bytes,
         distribution_date: u64,
-        expiration_date: OptionU64,
+        expiration_date: OptionalTimestamp,
+        valid_from: OptionalTimestamp,
+        cert_type: Bytes,
+        transferable: bool,
         revoked: bool,
+        withdrawn: bool,
     ) -> Chaincert {
         Chaincert {
             cid,
+            content_hash,
+            previous_cids: Vec::new(env),
+            attachments: Vec::new(env),
+            attributes: Map::new(env),
+            tags: Vec::new(env),
             distributor_contract,
             org_id,
             distribution_date,
             expiration_date,
+            expired: false,
+            valid_from,
+            cert_type,
+            transferable,
             revoked,
+            withdrawn,
+            visibility: Visibility::Public,
+            revocation_reason: RevocationReason::Unspecified,
+            revocation_date: OptionalTimestamp::None,
+            disputed: false,
+            dispute_reason: Bytes::new(env),
+            reinstatement_date: OptionalTimestamp::None,
+            restored: false,
+        }
+    }
+
+    /// Computes the status of the `Chaincert` against the current ledger timestamp
+    fn status(&self, env: &Env) -> CertStatus {
+        if self.revoked {
+            return CertStatus::Revoked;
+        }
+        if self.withdrawn {
+            return CertStatus::Withdrawn;
+        }
+        if self.restored {
+            return CertStatus::Restored;
+        }
+        if self.valid_from.is_future(env) {
+            return CertStatus::NotYetValid;
+        }
+        if self.expiration_date.is_expired(env) {
+            return CertStatus::Expired;
+        }
+        CertStatus::Valid
+    }
+    fn sort_value(&self, by: SortKey) -> u64 {
+        match by {
+            SortKey::DistributionDate => self.distribution_date,
+            SortKey::ExpirationDate => self.expiration_date.unwrap_or(u64::MAX),
         }
     }
 }
 
-pub(crate) fn deposit_chaincert(
-    env: &Env,
-    chaincert_id: Bytes,
-    cid: Bytes,
-    distributor_contract: Address,
-    org_id: Bytes,
-    distribution_date: u64,
-    expiration_date: OptionU64,
-) {
-    let chaincert = Chaincert::new(
-        cid,
-        distributor_contract,
-        org_id,
-        distribution_date,
-        expiration_date,
-        false,
-    );
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The status of a `Chaincert`, where expiration is computed against the ledger timestamp
+pub enum CertStatus {
+    Valid,
+    Revoked,
+    Withdrawn,
+    Expired,
+    /// The `Chaincert` becomes valid at a later date
+    NotYetValid,
+    /// The `Chaincert` was imported from a snapshot and is not attested again by the organization that issued it
+    Restored,
+}
 
-    let chaincerts = match env.storage().get(&CHAINCERT_KEY) {
-        Some(chaincert_map) => {
-            let mut chaincert_map: Map<Bytes, Chaincert> = chaincert_map.unwrap();
-            if !chaincert_map.contains_key(chaincert_id.clone()) {
-                chaincert_map.set(chaincert_id, chaincert);
-                chaincert_map
-            } else {
-                panic_with_error!(env, ContractError::ChaincertAlreadyInWallet)
-            }
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The visibility of a `Chaincert` in the listings of the wallet
+pub enum Visibility {
+    Public,
+    /// Only listed to the owner, or to the verifiers the `Chaincert` is shared with
+    Private,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The reason for revoking a `Chaincert`
+pub enum RevocationReason {
+    Unspecified,
+    /// The `Chaincert` was distributed by mistake or with wrong information
+    IssuedInError,
+    /// The `Chaincert` was replaced by a new one
+    Superseded,
+    /// The holder is no longer affiliated with the organization, e.g. an ended employment
+    AffiliationChanged,
+    /// The holder is no longer entitled to the privileges granted by the `Chaincert`
+    PrivilegeWithdrawn,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The number of `Chaincerts` stored in the wallet, grouped by status and by organization
+pub struct WalletStats {
+    pub total: u32,
+    pub valid: u32,
+    pub revoked: u32,
+    pub withdrawn: u32,
+    pub expired: u32,
+    pub not_yet_valid: u32,
+    pub restored: u32,
+    /// The number of `Chaincerts` distributed by each organization, identified by org_id
+    pub by_org: Map<Bytes, u32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The date used to sort the `Chaincerts` of the wallet, ties are ordered by chaincert_id
+pub enum SortKey {
+    DistributionDate,
+    /// `Chaincerts` without expiration date are sorted last
+    ExpirationDate,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A page of the `Chaincerts` along with the total number of `Chaincerts` stored in the wallet
+pub struct ChaincertPage {
+    /// The public `Chaincerts` of the requested page, ordered by chaincert_id
+    pub chaincerts: Vec<Chaincert>,
+    /// The total number of `Chaincerts` stored in the wallet, as returned by `chaincerts_count`
+    pub total: u32,
+}
+
+pub(crate) fn deposit_chaincert(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    let mut chaincert_ids: Vec<Bytes> = match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => chaincert_ids.unwrap(),
+        None => Vec::new(env),
+    };
+    if let Some(capacity) = env.storage().get(&CAPACITY_KEY) {
+        let capacity: u32 = capacity.unwrap();
+        if chaincert_ids.len() >= capacity {
+            panic_with_error!(env, ContractError::WalletFull)
         }
-        None => {
-            let map: Map<Bytes, Chaincert> = map![env, (chaincert_id, chaincert)];
-            map
+    }
+    match chaincert_ids.binary_search(chaincert_id) {
+        Ok(_) => panic_with_error!(env, ContractError::ChaincertAlreadyInWallet),
+        Err(index) => chaincert_ids.insert(index, chaincert_id.clone()),
+    }
+    history::record(env, chaincert_id, HistoryAction::Deposited, &chaincert.cid);
+    write_chaincert(env, chaincert_id, chaincert);
+    add_to_sorted_ids(env, chaincert_id, chaincert);
+    write_chaincert_ids(env, &chaincert_ids)
+}
+
+/// Stores the `Chaincerts` of a snapshot marked as restored, keeping their issuer attribution
+pub(crate) fn restore_chaincerts(env: &Env, chaincerts: &Map<Bytes, Chaincert>) {
+    if let Some(capacity) = env.storage().get(&CAPACITY_KEY) {
+        let capacity: u32 = capacity.unwrap();
+        if chaincerts.len() > capacity {
+            panic_with_error!(env, ContractError::WalletFull)
         }
-    };
-    write_chaincerts(env, &chaincerts)
+    }
+    let mut chaincert_ids: Vec<Bytes> = Vec::new(env);
+    for entry in chaincerts.iter() {
+        let (chaincert_id, mut chaincert) = entry.unwrap();
+        chaincert.restored = true;
+        history::record(env, &chaincert_id, HistoryAction::Restored, &chaincert.cid);
+        write_chaincert(env, &chaincert_id, &chaincert);
+        add_to_sorted_ids(env, &chaincert_id, &chaincert);
+        chaincert_ids.push_back(chaincert_id);
+    }
+    write_chaincert_ids(env, &chaincert_ids)
 }
 
 pub(crate) fn revoke_chaincert(
@@ -84,59 +259,651 @@ pub(crate) fn revoke_chaincert(
     chaincert_id: &Bytes,
     distributor_contract: &Address,
     org_id: &Bytes,
+    reason: RevocationReason,
 ) {
-    match env.storage().get(&CHAINCERT_KEY) {
-        Some(chaincert_map) => {
-            let mut chaincert_map: Map<Bytes, Chaincert> = chaincert_map.unwrap();
-            remove_chaincert_from_map(
-                env,
-                &mut chaincert_map,
-                chaincert_id,
-                distributor_contract,
-                org_id,
-            );
-            write_chaincerts(env, &chaincert_map);
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    chaincert.revoked = true;
+    chaincert.revocation_reason = reason;
+    chaincert.revocation_date = OptionalTimestamp::Some(env.ledger().timestamp());
+    history::record(env, chaincert_id, HistoryAction::Revoked, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+/// Reverts the revocation of a `Chaincert`, clearing its revocation and dispute details
+pub(crate) fn reinstate_chaincert(
+    env: &Env,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    if !chaincert.revoked {
+        panic_with_error!(env, ContractError::ChaincertNotRevoked)
+    }
+    chaincert.revoked = false;
+    chaincert.revocation_reason = RevocationReason::Unspecified;
+    chaincert.revocation_date = OptionalTimestamp::None;
+    chaincert.disputed = false;
+    chaincert.dispute_reason = Bytes::new(env);
+    chaincert.reinstatement_date = OptionalTimestamp::Some(env.ledger().timestamp());
+    history::record(env, chaincert_id, HistoryAction::Reinstated, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+/// Confirms a `Chaincert` imported from a snapshot, making it valid again
+pub(crate) fn attest_chaincert(
+    env: &Env,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    if !chaincert.restored {
+        panic_with_error!(env, ContractError::ChaincertNotRestored)
+    }
+    chaincert.restored = false;
+    history::record(env, chaincert_id, HistoryAction::Attested, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+/// Replaces the cid of a `Chaincert`, keeping the previous cid for audit
+pub(crate) fn update_chaincert_cid(
+    env: &Env,
+    chaincert_id: &Bytes,
+    new_cid: Bytes,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    chaincert.previous_cids.push_back(chaincert.cid.clone());
+    chaincert.cid = new_cid;
+    history::record(env, chaincert_id, HistoryAction::CidUpdated, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+pub(crate) fn add_attachment(
+    env: &Env,
+    chaincert_id: &Bytes,
+    cid: &Bytes,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    if chaincert.attachments.contains(cid) {
+        panic_with_error!(env, ContractError::AttachmentAlreadyAdded)
+    }
+    chaincert.attachments.push_back(cid.clone());
+    history::record(env, chaincert_id, HistoryAction::AttachmentAdded, cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+pub(crate) fn remove_attachment(
+    env: &Env,
+    chaincert_id: &Bytes,
+    cid: &Bytes,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    match chaincert.attachments.first_index_of(cid) {
+        Some(index) => chaincert.attachments.remove(index),
+        None => panic_with_error!(env, ContractError::AttachmentNotFound),
+    };
+    history::record(env, chaincert_id, HistoryAction::AttachmentRemoved, cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+/// Replaces the attributes of a `Chaincert`, up to `MAX_ATTRIBUTES` entries
+pub(crate) fn set_attributes(
+    env: &Env,
+    chaincert_id: &Bytes,
+    attributes: &Map<Bytes, Bytes>,
+    distributor_contract: &Address,
+    org_id: &Bytes,
+) {
+    if attributes.len() > MAX_ATTRIBUTES {
+        panic_with_error!(env, ContractError::TooManyAttributes)
+    }
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    check_issuer(env, &chaincert, distributor_contract, org_id);
+    chaincert.attributes = attributes.clone();
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+/// Marks a revoked `Chaincert` as disputed by the owner
+pub(crate) fn dispute_revocation(env: &Env, chaincert_id: &Bytes, reason: &Bytes) -> Chaincert {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    if !chaincert.revoked {
+        panic_with_error!(env, ContractError::ChaincertNotRevoked)
+    }
+    chaincert.disputed = true;
+    chaincert.dispute_reason = reason.clone();
+    history::record(env, chaincert_id, HistoryAction::Disputed, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+    chaincert
+}
+
+pub(crate) fn withdraw_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    chaincert.withdrawn = true;
+    history::record(env, chaincert_id, HistoryAction::Withdrawn, &chaincert.cid);
+    write_chaincert(env, chaincert_id, &chaincert);
+    chaincert
+}
+
+/// Removes a revoked, withdrawn or expired `Chaincert`, any `Chaincert` can be removed once the wallet is closed
+pub(crate) fn remove_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    let chaincert = read_chaincert(env, chaincert_id);
+    let removable = matches!(
+        chaincert.status(env),
+        CertStatus::Revoked | CertStatus::Withdrawn | CertStatus::Expired
+    );
+    if !removable && state::read_state(env) != WalletState::Closed {
+        panic_with_error!(env, ContractError::ChaincertNotRemovable)
+    }
+    delete_chaincert(env, chaincert_id);
+    chaincert
+}
+
+/// Removes a valid transferable `Chaincert` from the wallet to be deposited into another wallet
+pub(crate) fn take_transferable(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    let chaincert = read_chaincert(env, chaincert_id);
+    if !chaincert.transferable || chaincert.status(env) != CertStatus::Valid {
+        panic_with_error!(env, ContractError::ChaincertNotTransferable)
+    }
+    delete_chaincert(env, chaincert_id);
+    chaincert
+}
+
+/// Removes a `Chaincert` that is revoked and expired for longer than `SWEEP_DELAY`
+pub(crate) fn sweep_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    let chaincert = read_chaincert(env, chaincert_id);
+    let sweepable_at = chaincert
+        .expiration_date
+        .unwrap_or(u64::MAX)
+        .saturating_add(SWEEP_DELAY);
+    if !chaincert.revoked || sweepable_at > env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::ChaincertNotSweepable)
+    }
+    delete_chaincert(env, chaincert_id);
+    chaincert
+}
+
+pub(crate) fn write_capacity(env: &Env, capacity: u32) {
+    env.storage().set(&CAPACITY_KEY, &capacity)
+}
+
+pub(crate) fn remove_capacity(env: &Env) {
+    env.storage().remove(&CAPACITY_KEY)
+}
+
+pub(crate) fn write_grace_period(env: &Env, grace_period: u64) {
+    env.storage().set(&GRACE_PERIOD_KEY, &grace_period)
+}
+
+/// Removes the `Chaincerts` whose expiration date is reached, even if revoked or withdrawn, returning
+/// them identified by chaincert_id
+pub(crate) fn purge_expired(env: &Env) -> Map<Bytes, Chaincert> {
+    let mut purged: Map<Bytes, Chaincert> = Map::new(env);
+    let chaincert_ids: Vec<Bytes> = match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => chaincert_ids.unwrap(),
+        None => return purged,
+    };
+    for chaincert_id in chaincert_ids.iter() {
+        let chaincert_id = chaincert_id.unwrap();
+        let chaincert = read_chaincert(env, &chaincert_id);
+        if chaincert.expiration_date.is_expired(env) {
+            delete_chaincert(env, &chaincert_id);
+            purged.set(chaincert_id, chaincert);
         }
-        None => {
-            panic_with_error!(env, ContractError::NoChaincerts)
+    }
+    purged
+}
+
+/// Returns the public `Chaincerts`, private ones are only listed by `get_all_chaincerts`
+pub(crate) fn get_chaincerts(env: &Env) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_all_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.visibility == Visibility::Public {
+            chaincerts.push_back(chaincert);
         }
+    }
+    chaincerts
+}
+
+/// Returns the public `Chaincerts` along with their chaincert_id
+pub(crate) fn get_chaincert_entries(env: &Env) -> Vec<(Bytes, Chaincert)> {
+    let mut entries: Vec<(Bytes, Chaincert)> = Vec::new(env);
+    for chaincert_id in read_chaincert_ids(env).iter() {
+        let chaincert_id = chaincert_id.unwrap();
+        let chaincert = read_chaincert(env, &chaincert_id);
+        if chaincert.visibility == Visibility::Public {
+            entries.push_back((chaincert_id, chaincert));
+        }
+    }
+    entries
+}
+
+pub(crate) fn get_all_chaincerts(env: &Env) -> Vec<Chaincert> {
+    read_chaincerts(env, &read_chaincert_ids(env))
+}
+
+/// Returns all the `Chaincerts` identified by chaincert_id, empty when the wallet has no `Chaincerts`
+pub(crate) fn read_entries(env: &Env) -> Map<Bytes, Chaincert> {
+    let mut entries: Map<Bytes, Chaincert> = Map::new(env);
+    if let Some(chaincert_ids) = env.storage().get(&CHAINCERT_IDS_KEY) {
+        let chaincert_ids: Vec<Bytes> = chaincert_ids.unwrap();
+        for chaincert_id in chaincert_ids.iter() {
+            let chaincert_id = chaincert_id.unwrap();
+            let chaincert = read_chaincert(env, &chaincert_id);
+            entries.set(chaincert_id, chaincert);
+        }
+    }
+    entries
+}
+
+pub(crate) fn set_visibility(env: &Env, chaincert_id: &Bytes, visibility: Visibility) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    chaincert.visibility = visibility;
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+pub(crate) fn add_tag(env: &Env, chaincert_id: &Bytes, tag: &Bytes) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    if chaincert.tags.contains(tag) {
+        panic_with_error!(env, ContractError::TagAlreadyAdded)
+    }
+    chaincert.tags.push_back(tag.clone());
+    write_chaincert(env, chaincert_id, &chaincert);
+}
+
+pub(crate) fn remove_tag(env: &Env, chaincert_id: &Bytes, tag: &Bytes) {
+    let mut chaincert = read_chaincert(env, chaincert_id);
+    match chaincert.tags.first_index_of(tag) {
+        Some(index) => chaincert.tags.remove(index),
+        None => panic_with_error!(env, ContractError::TagNotFound),
     };
+    write_chaincert(env, chaincert_id, &chaincert);
 }
 
-pub(crate) fn get_chaincerts(env: &Env) -> Vec<Chaincert> {
-    read_chaincerts(env).values()
+/// Sets the private note of the owner on a `Chaincert`, an empty note removes it
+pub(crate) fn set_note(env: &Env, chaincert_id: &Bytes, note: &Bytes) {
+    if note.len() > MAX_NOTE_LENGTH {
+        panic_with_error!(env, ContractError::NoteTooLong)
+    }
+    read_chaincert(env, chaincert_id);
+    let key = DataKey::ChaincertNote(chaincert_id.clone());
+    if note.is_empty() {
+        env.storage().remove(&key)
+    } else {
+        env.storage().set(&key, note)
+    }
 }
 
-fn remove_chaincert_from_map(
+pub(crate) fn get_note(env: &Env, chaincert_id: &Bytes) -> Bytes {
+    read_chaincert(env, chaincert_id);
+    match env
+        .storage()
+        .get(&DataKey::ChaincertNote(chaincert_id.clone()))
+    {
+        Some(note) => note.unwrap(),
+        None => Bytes::new(env),
+    }
+}
+
+pub(crate) fn chaincerts_count(env: &Env) -> u32 {
+    match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => {
+            let chaincert_ids: Vec<Bytes> = chaincert_ids.unwrap();
+            chaincert_ids.len()
+        }
+        None => 0,
+    }
+}
+
+/// Counts the `Chaincerts` the reader can read, all of them when the reader is the owner
+pub(crate) fn get_stats(env: &Env, reader: &Option<Address>) -> WalletStats {
+    let mut stats = WalletStats {
+        total: 0,
+        valid: 0,
+        revoked: 0,
+        withdrawn: 0,
+        expired: 0,
+        not_yet_valid: 0,
+        restored: 0,
+        by_org: Map::new(env),
+    };
+    let chaincert_ids: Vec<Bytes> = match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => chaincert_ids.unwrap(),
+        None => return stats,
+    };
+    if let Some(reader) = reader {
+        reader.require_auth();
+    }
+    for chaincert_id in chaincert_ids.iter() {
+        let chaincert_id = chaincert_id.unwrap();
+        let chaincert = read_chaincert(env, &chaincert_id);
+        if !is_readable(env, &chaincert_id, &chaincert, reader) {
+            continue;
+        }
+        stats.total += 1;
+        match chaincert.status(env) {
+            CertStatus::Valid => stats.valid += 1,
+            CertStatus::Revoked => stats.revoked += 1,
+            CertStatus::Withdrawn => stats.withdrawn += 1,
+            CertStatus::Expired => stats.expired += 1,
+            CertStatus::NotYetValid => stats.not_yet_valid += 1,
+            CertStatus::Restored => stats.restored += 1,
+        }
+        let org_count = match stats.by_org.get(chaincert.org_id.clone()) {
+            Some(org_count) => org_count.unwrap(),
+            None => 0,
+        };
+        stats.by_org.set(chaincert.org_id, org_count + 1);
+    }
+    stats
+}
+
+pub(crate) fn get_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    read_chaincert(env, chaincert_id)
+}
+
+/// Returns a `Chaincert` the reader can read, authenticating the reader when given. Private `Chaincerts`
+/// are only readable by the owner and, while the wallet is active, the verifiers they are shared with
+pub(crate) fn get_readable_chaincert(
+    env: &Env,
+    chaincert_id: &Bytes,
+    reader: &Option<Address>,
+) -> Chaincert {
+    if let Some(reader) = reader {
+        reader.require_auth();
+    }
+    let chaincert = read_chaincert(env, chaincert_id);
+    if !is_readable(env, chaincert_id, &chaincert, reader) {
+        panic_with_error!(env, ContractError::NotAuthorized)
+    }
+    chaincert
+}
+
+pub(crate) fn get_chaincert_history(
+    env: &Env,
+    chaincert_id: &Bytes,
+    reader: &Option<Address>,
+) -> Vec<HistoryEntry> {
+    get_readable_chaincert(env, chaincert_id, reader);
+    history::read_history(env, chaincert_id)
+}
+
+/// Checks the content hash matches the one committed by the organization
+pub(crate) fn verify_content(
+    env: &Env,
+    chaincert_id: &Bytes,
+    content_hash: &BytesN<32>,
+    reader: &Option<Address>,
+) -> bool {
+    get_readable_chaincert(env, chaincert_id, reader).content_hash == content_hash.clone()
+}
+
+/// Checks the `Chaincert` is valid, expired `Chaincerts` are still valid during the grace period
+pub(crate) fn is_chaincert_valid(
     env: &Env,
-    chaincert_map: &mut Map<Bytes, Chaincert>,
     chaincert_id: &Bytes,
+    reader: &Option<Address>,
+) -> bool {
+    let chaincert = get_readable_chaincert(env, chaincert_id, reader);
+    match chaincert.status(env) {
+        CertStatus::Valid => true,
+        CertStatus::Expired => {
+            let grace_period: u64 = match env.storage().get(&GRACE_PERIOD_KEY) {
+                Some(grace_period) => grace_period.unwrap(),
+                None => 0,
+            };
+            chaincert
+                .expiration_date
+                .unwrap_or(0)
+                .saturating_add(grace_period)
+                > env.ledger().timestamp()
+        }
+        _ => false,
+    }
+}
+
+/// Reads only the `Chaincerts` of the requested range of the chaincert_id index, private `Chaincerts`
+/// within the range are left out of the page
+pub(crate) fn get_chaincerts_paginated(env: &Env, offset: u32, limit: u32) -> ChaincertPage {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    let chaincert_ids: Vec<Bytes> = match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => chaincert_ids.unwrap(),
+        None => {
+            return ChaincertPage {
+                chaincerts,
+                total: 0,
+            }
+        }
+    };
+    let total = chaincert_ids.len();
+    let start = offset.min(total);
+    let end = start.saturating_add(limit).min(total);
+    for chaincert_id in chaincert_ids.slice(start..end).iter() {
+        let chaincert = read_chaincert(env, &chaincert_id.unwrap());
+        if chaincert.visibility == Visibility::Public {
+            chaincerts.push_back(chaincert);
+        }
+    }
+
+    ChaincertPage { chaincerts, total }
+}
+
+/// Reads only the `Chaincerts` of the requested range of the index sorted by the given date, private
+/// `Chaincerts` within the range are left out of the page
+pub(crate) fn get_chaincerts_sorted(
+    env: &Env,
+    by: SortKey,
+    offset: u32,
+    limit: u32,
+) -> ChaincertPage {
+    let sorted_ids = read_sorted_ids(env, by);
+    let total = sorted_ids.len();
+    let start = offset.min(total);
+    let end = start.saturating_add(limit).min(total);
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for entry in sorted_ids.slice(start..end).iter() {
+        let (_, chaincert_id) = entry.unwrap();
+        let chaincert = read_chaincert(env, &chaincert_id);
+        if chaincert.visibility == Visibility::Public {
+            chaincerts.push_back(chaincert);
+        }
+    }
+
+    ChaincertPage { chaincerts, total }
+}
+
+pub(crate) fn get_chaincerts_by_org(env: &Env, org_id: &Bytes) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.org_id == org_id.clone() {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+pub(crate) fn get_chaincerts_by_distributor(
+    env: &Env,
     distributor_contract: &Address,
-    org_id: &Bytes,
-) {
-    match chaincert_map.get(chaincert_id.clone()) {
-        Some(chaincert) => {
-            let mut chaincert = chaincert.unwrap();
-            if chaincert.distributor_contract == distributor_contract.clone()
-                && chaincert.org_id == org_id.clone()
-            {
-                chaincert.revoked = true;
-                chaincert_map.set(chaincert_id.clone(), chaincert);
-            } else {
-                panic_with_error!(env, ContractError::NotAuthorized);
+) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.distributor_contract == distributor_contract.clone() {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+pub(crate) fn get_chaincerts_by_type(env: &Env, cert_type: &Bytes) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.cert_type == cert_type.clone() {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+pub(crate) fn get_chaincerts_by_tag(env: &Env, tag: &Bytes) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.tags.contains(tag) {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+pub(crate) fn get_chaincerts_by_status(env: &Env, status: CertStatus) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert in get_chaincerts(env).iter() {
+        let chaincert = chaincert.unwrap();
+        if chaincert.status(env) == status {
+            chaincerts.push_back(chaincert);
+        }
+    }
+    chaincerts
+}
+
+fn is_readable(
+    env: &Env,
+    chaincert_id: &Bytes,
+    chaincert: &Chaincert,
+    reader: &Option<Address>,
+) -> bool {
+    if chaincert.visibility == Visibility::Public {
+        return true;
+    }
+    match reader {
+        Some(reader) => {
+            reader.clone() == owner::read_owner(env)
+                || (state::read_state(env) == WalletState::Active
+                    && access_grant::is_shared_with(env, reader, chaincert_id))
+        }
+        None => false,
+    }
+}
+
+/// Checks that the distributor_contract and org_id pair issued the `Chaincert`
+fn check_issuer(env: &Env, chaincert: &Chaincert, distributor_contract: &Address, org_id: &Bytes) {
+    if chaincert.distributor_contract != distributor_contract.clone()
+        || chaincert.org_id != org_id.clone()
+    {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
+/// Reads a `Chaincert` from its own storage entry, if it is stored in the wallet
+pub(crate) fn find_chaincert(env: &Env, chaincert_id: &Bytes) -> Option<Chaincert> {
+    env.storage()
+        .get(&DataKey::Chaincert(chaincert_id.clone()))
+        .map(|chaincert| with_expiration(env, chaincert.unwrap()))
+}
+
+/// Reads a `Chaincert` from its own storage entry
+fn read_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    match env.storage().get(&DataKey::Chaincert(chaincert_id.clone())) {
+        Some(chaincert) => with_expiration(env, chaincert.unwrap()),
+        None => {
+            if !env.storage().has(&CHAINCERT_IDS_KEY) {
+                panic_with_error!(env, ContractError::NoChaincerts)
             }
+            panic_with_error!(env, ContractError::ChaincertNotFound)
+        }
+    }
+}
+
+fn delete_chaincert(env: &Env, chaincert_id: &Bytes) {
+    remove_from_sorted_ids(env, chaincert_id, &read_chaincert(env, chaincert_id));
+    let mut chaincert_ids = read_chaincert_ids(env);
+    if let Ok(index) = chaincert_ids.binary_search(chaincert_id) {
+        chaincert_ids.remove(index);
+    }
+    env.storage()
+        .remove(&DataKey::Chaincert(chaincert_id.clone()));
+    history::remove_history(env, chaincert_id);
+    endorsement::remove_endorsements(env, chaincert_id);
+    env.storage()
+        .remove(&DataKey::ChaincertNote(chaincert_id.clone()));
+    write_chaincert_ids(env, &chaincert_ids);
+}
+
+/// Sets the `expired` indicator of a `Chaincert` against the current ledger timestamp
+pub(crate) fn with_expiration(env: &Env, mut chaincert: Chaincert) -> Chaincert {
+    chaincert.expired = chaincert.expiration_date.is_expired(env);
+    chaincert
+}
+
+pub(crate) fn write_chaincert(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    env.storage()
+        .set(&DataKey::Chaincert(chaincert_id.clone()), chaincert)
+}
+
+fn read_chaincerts(env: &Env, chaincert_ids: &Vec<Bytes>) -> Vec<Chaincert> {
+    let mut chaincerts: Vec<Chaincert> = Vec::new(env);
+    for chaincert_id in chaincert_ids.iter() {
+        chaincerts.push_back(read_chaincert(env, &chaincert_id.unwrap()));
+    }
+    chaincerts
+}
+
+/// Adds the `Chaincert` to the indexes sorted by each `SortKey`, ties are ordered by chaincert_id
+pub(crate) fn add_to_sorted_ids(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    for by in [SortKey::DistributionDate, SortKey::ExpirationDate] {
+        let mut sorted_ids = read_sorted_ids(env, by);
+        let entry = (chaincert.sort_value(by), chaincert_id.clone());
+        if let Err(index) = sorted_ids.binary_search(&entry) {
+            sorted_ids.insert(index, entry);
         }
-        None => panic_with_error!(env, ContractError::ChaincertNotFound),
+        env.storage()
+            .set(&DataKey::SortedChaincertIds(by), &sorted_ids)
+    }
+}
+
+fn remove_from_sorted_ids(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    for by in [SortKey::DistributionDate, SortKey::ExpirationDate] {
+        let mut sorted_ids = read_sorted_ids(env, by);
+        let entry = (chaincert.sort_value(by), chaincert_id.clone());
+        if let Ok(index) = sorted_ids.binary_search(&entry) {
+            sorted_ids.remove(index);
+        }
+        env.storage()
+            .set(&DataKey::SortedChaincertIds(by), &sorted_ids)
+    }
+}
+
+fn read_sorted_ids(env: &Env, by: SortKey) -> Vec<(u64, Bytes)> {
+    match env.storage().get(&DataKey::SortedChaincertIds(by)) {
+        Some(sorted_ids) => sorted_ids.unwrap(),
+        None => Vec::new(env),
     }
 }
 
-fn read_chaincerts(env: &Env) -> Map<Bytes, Chaincert> {
-    match env.storage().get(&CHAINCERT_KEY) {
-        Some(cc) => cc.unwrap(),
+fn read_chaincert_ids(env: &Env) -> Vec<Bytes> {
+    match env.storage().get(&CHAINCERT_IDS_KEY) {
+        Some(chaincert_ids) => chaincert_ids.unwrap(),
         None => panic_with_error!(env, ContractError::NoChaincerts),
     }
 }
 
-fn write_chaincerts(env: &Env, certs: &Map<Bytes, Chaincert>) {
-    env.storage().set(&CHAINCERT_KEY, certs)
+pub(crate) fn write_chaincert_ids(env: &Env, chaincert_ids: &Vec<Bytes>) {
+    env.storage().set(&CHAINCERT_IDS_KEY, chaincert_ids)
 }
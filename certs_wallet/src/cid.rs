@@ -0,0 +1,54 @@
+//! Module CID
+//!
+//! Module responsible of the sanity checks over the IPFS content ids (CID) of the `Chaincerts`
+use soroban_sdk::{panic_with_error, Bytes, Env};
+
+use crate::error::ContractError;
+
+/// Length of a CIDv0, a base58btc encoded sha2-256 multihash
+const CID_V0_LENGTH: u32 = 46;
+/// Bounds of the length of a multibase encoded CIDv1
+const CID_V1_MIN_LENGTH: u32 = 10;
+const CID_V1_MAX_LENGTH: u32 = 128;
+
+/// Checks that the cid looks like a CIDv0 (`Qm...`) or a base32, base58btc or base16 CIDv1
+pub(crate) fn check_cid(env: &Env, cid: &Bytes) {
+    if !is_cid_v0(cid) && !is_cid_v1(cid) {
+        panic_with_error!(env, ContractError::InvalidCID)
+    }
+}
+
+fn is_cid_v0(cid: &Bytes) -> bool {
+    cid.len() == CID_V0_LENGTH
+        && cid.get_unchecked(0) == b'Q'
+        && cid.get_unchecked(1) == b'm'
+        && has_only(cid, 0, is_base58btc)
+}
+
+fn is_cid_v1(cid: &Bytes) -> bool {
+    if cid.len() < CID_V1_MIN_LENGTH || cid.len() > CID_V1_MAX_LENGTH {
+        return false;
+    }
+    match cid.get_unchecked(0) {
+        b'b' => has_only(cid, 1, is_base32),
+        b'z' => has_only(cid, 1, is_base58btc),
+        b'f' => has_only(cid, 1, is_base16),
+        _ => false,
+    }
+}
+
+fn has_only(cid: &Bytes, from: u32, is_valid: fn(u8) -> bool) -> bool {
+    (from..cid.len()).all(|index| is_valid(cid.get_unchecked(index)))
+}
+
+fn is_base58btc(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() && !matches!(byte, b'0' | b'O' | b'I' | b'l')
+}
+
+fn is_base32(byte: u8) -> bool {
+    matches!(byte, b'a'..=b'z' | b'2'..=b'7')
+}
+
+fn is_base16(byte: u8) -> bool {
+    matches!(byte, b'0'..=b'9' | b'a'..=b'f')
+}
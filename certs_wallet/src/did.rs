@@ -0,0 +1,36 @@
+//! Module DID
+//!
+//! Module responsible of linking the wallet to the `did_contract` that holds the DID document of the owner
+use soroban_sdk::{contractclient, panic_with_error, Address, Env};
+
+use crate::{error::ContractError, owner, storage_types::DataKey};
+
+const DID_CONTRACT_KEY: DataKey = DataKey::DIDContract;
+
+#[allow(dead_code)]
+#[contractclient(name = "DIDContractClient")]
+/// The interface of the `did_contract` used by the wallet
+pub trait DIDContract {
+    /// Returns whether the address is a controller of the DID document
+    fn is_controller(env: Env, address: Address) -> bool;
+}
+
+pub(crate) fn read_did_contract(env: &Env) -> Address {
+    match env.storage().get(&DID_CONTRACT_KEY) {
+        Some(did_contract) => did_contract.unwrap(),
+        None => panic_with_error!(env, ContractError::NoDIDContract),
+    }
+}
+
+/// Links the DID contract, which must acknowledge the wallet owner as a controller of the DID
+pub(crate) fn write_did_contract(env: &Env, did_contract: &Address) {
+    let contract_id = match did_contract.contract_id() {
+        Some(contract_id) => contract_id,
+        None => panic_with_error!(env, ContractError::NotDIDController),
+    };
+    let client = DIDContractClient::new(env, &contract_id);
+    if !client.is_controller(&owner::read_owner(env)) {
+        panic_with_error!(env, ContractError::NotDIDController)
+    }
+    env.storage().set(&DID_CONTRACT_KEY, did_contract)
+}
@@ -0,0 +1,53 @@
+//! Module Endorsement
+//!
+//! Module responsible of managing the endorsements other organizations of the ACL attach to the
+//! `Chaincerts` of the wallet, e.g. an accreditation body endorsing a university's diploma.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Vec};
+
+use crate::{chaincert, error::ContractError, storage_types::DataKey};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// An endorsement of a `Chaincert`, authorized by the endorser set for the organization in the ACL
+pub struct Endorsement {
+    pub org_id: Bytes,
+    /// Address of the contract that signed the endorsement on behalf of the organization
+    pub endorser: Address,
+    /// The endorsement date in Unix Timestamp format
+    pub endorsed_at: u64,
+}
+
+/// Endorses a `Chaincert`, an organization can't endorse its own `Chaincerts` nor endorse them twice
+pub(crate) fn endorse(env: &Env, chaincert_id: &Bytes, endorser: &Address, org_id: &Bytes) {
+    if chaincert::get_chaincert(env, chaincert_id).org_id == org_id.clone() {
+        panic_with_error!(env, ContractError::NotAuthorized)
+    }
+    let mut endorsements = read_endorsements(env, chaincert_id);
+    for endorsement in endorsements.iter() {
+        if endorsement.unwrap().org_id == org_id.clone() {
+            panic_with_error!(env, ContractError::AlreadyEndorsed)
+        }
+    }
+    endorsements.push_back(Endorsement {
+        org_id: org_id.clone(),
+        endorser: endorser.clone(),
+        endorsed_at: env.ledger().timestamp(),
+    });
+    env.storage()
+        .set(&DataKey::Endorsements(chaincert_id.clone()), &endorsements)
+}
+
+pub(crate) fn read_endorsements(env: &Env, chaincert_id: &Bytes) -> Vec<Endorsement> {
+    match env
+        .storage()
+        .get(&DataKey::Endorsements(chaincert_id.clone()))
+    {
+        Some(endorsements) => endorsements.unwrap(),
+        None => Vec::new(env),
+    }
+}
+
+pub(crate) fn remove_endorsements(env: &Env, chaincert_id: &Bytes) {
+    env.storage()
+        .remove(&DataKey::Endorsements(chaincert_id.clone()))
+}
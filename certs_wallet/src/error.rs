@@ -12,4 +12,45 @@ pub enum ContractError {
     ChaincertAlreadyInWallet = 9,
     ChaincertNotFound = 10,
     NoChaincerts = 11,
+    NoPendingOwner = 12,
+    AlreadyOwner = 13,
+    OwnerNotFound = 14,
+    InvalidThreshold = 15,
+    NotEnoughApprovals = 16,
+    ChaincertNotRemovable = 17,
+    NotDIDController = 18,
+    NoDIDContract = 19,
+    AlreadyMigrated = 20,
+    InvalidCID = 21,
+    AttachmentAlreadyAdded = 22,
+    AttachmentNotFound = 23,
+    PresentationAlreadyExists = 24,
+    PresentationNotFound = 25,
+    ChallengeAlreadyExists = 26,
+    ChallengeNotFound = 27,
+    WalletFrozen = 28,
+    WalletClosed = 29,
+    RecoveryInProgress = 30,
+    NoRecoveryInProgress = 31,
+    RecoveryTimelocked = 32,
+    RateLimitExceeded = 33,
+    WalletFull = 34,
+    NoAclAdmin = 35,
+    TooManyAttributes = 36,
+    AlreadyEndorsed = 37,
+    TagAlreadyAdded = 38,
+    TagNotFound = 39,
+    ChaincertNotRevoked = 40,
+    ChaincertAlreadyPending = 41,
+    PendingChaincertNotFound = 42,
+    OfferExpired = 43,
+    ChaincertNotTransferable = 44,
+    ChaincertNotSweepable = 45,
+    OrganizationSuspended = 46,
+    NoteTooLong = 47,
+    WalletNotEmpty = 48,
+    InvalidDestinationWallet = 49,
+    ChallengeAlreadyProved = 50,
+    ChaincertNotRestored = 51,
+    TransferNotApproved = 52,
 }
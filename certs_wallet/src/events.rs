@@ -0,0 +1,208 @@
+//! Module Events
+//!
+//! Module responsible of publishing the events emitted by the wallet, so indexers can follow its changes.
+use soroban_sdk::{Address, Bytes, Env, Symbol};
+
+pub(crate) fn initialize(env: &Env, owner: &Address) {
+    let topics = (Symbol::short("init"), owner.clone());
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn close_wallet(env: &Env, owner: &Address, successor: &Option<Address>) {
+    let topics = (Symbol::short("close"), owner.clone());
+    env.events().publish(topics, successor.clone());
+}
+
+pub(crate) fn add_organization(env: &Env, owner: &Address, org_id: &Bytes) {
+    let topics = (Symbol::short("add_org"), owner.clone(), org_id.clone());
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn remove_organization(env: &Env, owner: &Address, org_id: &Bytes) {
+    let topics = (Symbol::short("rm_org"), owner.clone(), org_id.clone());
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn suspend_organization(env: &Env, owner: &Address, org_id: &Bytes) {
+    let topics = (Symbol::short("suspend"), owner.clone(), org_id.clone());
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn resume_organization(env: &Env, owner: &Address, org_id: &Bytes) {
+    let topics = (Symbol::short("resume"), owner.clone(), org_id.clone());
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn deposit_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+) {
+    let topics = (
+        Symbol::short("deposit"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, distributor_contract.clone());
+}
+
+pub(crate) fn offer_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+) {
+    let topics = (
+        Symbol::short("offer"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, distributor_contract.clone());
+}
+
+pub(crate) fn reject_chaincert(env: &Env, owner: &Address, org_id: &Bytes, chaincert_id: &Bytes) {
+    let topics = (
+        Symbol::short("reject"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn transfer_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    destination_wallet: &Address,
+) {
+    let topics = (
+        Symbol::short("transfer"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, destination_wallet.clone());
+}
+
+pub(crate) fn update_chaincert_cid(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    new_cid: &Bytes,
+) {
+    let topics = (
+        Symbol::short("update"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, new_cid.clone());
+}
+
+pub(crate) fn endorse_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    endorser: &Address,
+) {
+    let topics = (
+        Symbol::short("endorse"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, endorser.clone());
+}
+
+pub(crate) fn revoke_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+) {
+    let topics = (
+        Symbol::short("revoke"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, distributor_contract.clone());
+}
+
+pub(crate) fn reinstate_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+) {
+    let topics = (
+        Symbol::short("reinstate"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, distributor_contract.clone());
+}
+
+pub(crate) fn attest_chaincert(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    distributor_contract: &Address,
+) {
+    let topics = (
+        Symbol::short("attest"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, distributor_contract.clone());
+}
+
+pub(crate) fn withdraw_chaincert(env: &Env, owner: &Address, org_id: &Bytes, chaincert_id: &Bytes) {
+    let topics = (
+        Symbol::short("withdraw"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn remove_chaincert(env: &Env, owner: &Address, org_id: &Bytes, chaincert_id: &Bytes) {
+    let topics = (
+        Symbol::short("remove"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn dispute_revocation(
+    env: &Env,
+    owner: &Address,
+    org_id: &Bytes,
+    chaincert_id: &Bytes,
+    reason: &Bytes,
+) {
+    let topics = (
+        Symbol::short("dispute"),
+        owner.clone(),
+        org_id.clone(),
+        chaincert_id.clone(),
+    );
+    env.events().publish(topics, reason.clone());
+}
@@ -0,0 +1,66 @@
+//! Module History
+//!
+//! Module responsible of keeping a bounded record of the changes of each `Chaincert` for audit purposes.
+use soroban_sdk::{contracttype, Bytes, Env, Vec};
+
+use crate::storage_types::DataKey;
+
+/// Maximum number of entries kept per `Chaincert`, the oldest entries are discarded first
+const MAX_HISTORY_ENTRIES: u32 = 20;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The changes recorded in the history of a `Chaincert`
+pub enum HistoryAction {
+    Deposited,
+    CidUpdated,
+    AttachmentAdded,
+    AttachmentRemoved,
+    Revoked,
+    Withdrawn,
+    Disputed,
+    Reinstated,
+    /// Imported from the snapshot of another wallet
+    Restored,
+    /// Confirmed by the issuer after being restored
+    Attested,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A change of a `Chaincert` along with its cid after the change, or the attachment cid involved
+pub struct HistoryEntry {
+    pub action: HistoryAction,
+    pub cid: Bytes,
+    /// The ledger timestamp of the change in Unix Timestamp format
+    pub timestamp: u64,
+}
+
+pub(crate) fn read_history(env: &Env, chaincert_id: &Bytes) -> Vec<HistoryEntry> {
+    match env
+        .storage()
+        .get(&DataKey::ChaincertHistory(chaincert_id.clone()))
+    {
+        Some(history) => history.unwrap(),
+        None => Vec::new(env),
+    }
+}
+
+pub(crate) fn record(env: &Env, chaincert_id: &Bytes, action: HistoryAction, cid: &Bytes) {
+    let mut history = read_history(env, chaincert_id);
+    if history.len() >= MAX_HISTORY_ENTRIES {
+        history.pop_front();
+    }
+    history.push_back(HistoryEntry {
+        action,
+        cid: cid.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage()
+        .set(&DataKey::ChaincertHistory(chaincert_id.clone()), &history)
+}
+
+pub(crate) fn remove_history(env: &Env, chaincert_id: &Bytes) {
+    env.storage()
+        .remove(&DataKey::ChaincertHistory(chaincert_id.clone()))
+}
@@ -0,0 +1,40 @@
+//! Module Hook
+//!
+//! Module responsible of notifying the hook contract registered by the owner about the `Chaincerts`
+//! stored in the wallet, enabling downstream automation without polling. Revocations are not notified
+//! since a hook exhausting the budget would block the organizations from revoking, they are tracked
+//! through the wallet events.
+use soroban_sdk::{contractclient, Address, Bytes, Env};
+
+use crate::storage_types::DataKey;
+
+const HOOK_KEY: DataKey = DataKey::Hook;
+
+#[allow(dead_code)]
+#[contractclient(name = "WalletHookClient")]
+/// The interface a hook contract must implement to be notified by the wallet
+pub trait WalletHook {
+    /// Called after a `Chaincert` is deposited into the wallet, or accepted by the owner when offered
+    fn on_deposit(env: Env, wallet: Address, chaincert_id: Bytes, org_id: Bytes);
+}
+
+pub(crate) fn write_hook(env: &Env, hook: &Address) {
+    env.storage().set(&HOOK_KEY, hook)
+}
+
+pub(crate) fn remove_hook(env: &Env) {
+    env.storage().remove(&HOOK_KEY)
+}
+
+/// Notifies the hook about a stored `Chaincert`, a failing hook doesn't affect the deposit
+pub(crate) fn notify_deposit(env: &Env, chaincert_id: &Bytes, org_id: &Bytes) {
+    if let Some(client) = read_hook_client(env) {
+        let _ = client.try_on_deposit(&env.current_contract_address(), chaincert_id, org_id);
+    }
+}
+
+fn read_hook_client(env: &Env) -> Option<WalletHookClient> {
+    let hook: Address = env.storage().get(&HOOK_KEY)?.unwrap();
+    hook.contract_id()
+        .map(|contract_id| WalletHookClient::new(env, &contract_id))
+}
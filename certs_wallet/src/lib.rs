@@ -1,58 +1,684 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 mod access_control_list;
+mod access_grant;
+mod audit;
 mod chaincert;
+mod cid;
+mod did;
+mod endorsement;
 mod error;
+mod events;
+mod history;
+mod hook;
+mod metadata;
+mod migration;
 mod option;
 mod owner;
+mod ownership_proof;
+mod pending;
+mod presentation;
+mod rate_limit;
+mod recovery;
+mod snapshot;
+mod state;
 mod storage_types;
+mod sweep;
+mod transfer;
 use crate::error::ContractError;
-use chaincert::Chaincert;
-use option::OptionU64;
-use soroban_sdk::{contractimpl, panic_with_error, Address, Bytes, Env, Vec};
+use access_control_list::{OrgEntry, OrgInfo, Permission};
+use audit::AuditPage;
+use chaincert::{
+    CertStatus, Chaincert, ChaincertPage, RevocationReason, SortKey, Visibility, WalletStats,
+};
+use endorsement::Endorsement;
+use history::HistoryEntry;
+use metadata::WalletMetadata;
+use migration::WalletVersion;
+use option::OptionalTimestamp;
+use ownership_proof::OwnershipProof;
+use pending::PendingChaincert;
+use presentation::Presentation;
+use recovery::{Guardians, Recovery};
+use snapshot::WalletSnapshot;
+use soroban_sdk::{
+    contractimpl, panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Map, RawVal, Symbol, Vec,
+};
+use state::WalletState;
+use sweep::SweepBounty;
+use transfer::TransferApproval;
 
 pub struct Wallet;
 
 #[contractimpl]
 impl Wallet {
-    pub fn initialize(env: Env, owner: Address) {
+    /// Initialize the wallet with its owner and descriptive metadata (label and owner DID)
+    pub fn initialize(env: Env, owner: Address, label: Bytes, owner_did: Bytes) {
         if owner::has_owner(&env) {
             panic_with_error!(env, ContractError::AlreadyInit);
         }
         owner::write_owner(&env, &owner);
+        metadata::write_metadata(&env, label, owner_did);
+        migration::write_version(&env, migration::STORAGE_VERSION);
+        events::initialize(&env, &owner);
     }
 
-    /// Add organizations to the ACL
-    pub fn add_organization(env: Env, org_id: Bytes) {
+    /// Update the label and owner DID of the wallet metadata
+    pub fn update_metadata(env: Env, label: Bytes, owner_did: Bytes) {
         owner::read_owner(&env).require_auth();
-        access_control_list::add_organization(&env, &org_id)
+        metadata::write_metadata(&env, label, owner_did)
+    }
+
+    /// Get the wallet metadata
+    pub fn get_metadata(env: Env) -> WalletMetadata {
+        metadata::read_metadata(&env)
+    }
+
+    /// Migrate the storage of the wallet from a previous layout to the current version
+    pub fn migrate(env: Env) {
+        owner::require_owners_auth(&env, "migrate", Vec::new(&env));
+        migration::migrate(&env)
+    }
+
+    /// Get the version of the wallet contract and the storage version of the wallet
+    pub fn version(env: Env) -> WalletVersion {
+        migration::read_wallet_version(&env)
+    }
+
+    /// Export the owner, ACL, `Chaincerts`, metadata and version of the wallet in a single value
+    pub fn export_snapshot(env: Env) -> WalletSnapshot {
+        owner::read_owner(&env).require_auth();
+        snapshot::export_snapshot(&env)
+    }
+
+    /// Import a snapshot exported from another wallet, only allowed while the wallet has no organizations or `Chaincerts`.
+    /// The imported `Chaincerts` must be issued by organizations of the snapshot ACL and are not valid until their
+    /// issuers attest them
+    pub fn import_snapshot(env: Env, snapshot: WalletSnapshot) {
+        state::check_active(&env);
+        owner::require_owners_auth(&env, "import_snapshot", (snapshot.clone(),).into_val(&env));
+        snapshot::import_snapshot(&env, &snapshot)
+    }
+
+    /// Link the wallet to the `did_contract` that holds the DID document of the owner
+    pub fn set_did(env: Env, did_contract: Address) {
+        owner::require_owners_auth(&env, "set_did", (did_contract.clone(),).into_val(&env));
+        did::write_did_contract(&env, &did_contract)
+    }
+
+    /// Get the address of the `did_contract` linked to the wallet
+    pub fn get_did(env: Env) -> Address {
+        did::read_did_contract(&env)
+    }
+
+    /// Freeze the wallet, blocking deposits, ACL changes and the sharing of `Chaincerts`
+    pub fn freeze(env: Env) {
+        state::check_not_closed(&env);
+        owner::require_owners_auth(&env, "freeze", Vec::new(&env));
+        state::write_state(&env, WalletState::Frozen)
+    }
+
+    /// Unfreeze the wallet
+    pub fn unfreeze(env: Env) {
+        state::check_not_closed(&env);
+        owner::require_owners_auth(&env, "unfreeze", Vec::new(&env));
+        state::write_state(&env, WalletState::Active)
+    }
+
+    /// Close the wallet permanently, optionally pointing to a successor wallet. The ACL is kept so the organizations
+    /// can still revoke the `Chaincerts` they issued
+    pub fn close_wallet(env: Env, successor: Option<Address>) {
+        state::check_not_closed(&env);
+        owner::require_owners_auth(&env, "close_wallet", (successor.clone(),).into_val(&env));
+        state::write_state(&env, WalletState::Closed);
+        events::close_wallet(&env, &owner::read_owner(&env), &successor)
+    }
+
+    /// Get the lifecycle state of the wallet
+    pub fn get_state(env: Env) -> WalletState {
+        state::read_state(&env)
+    }
+
+    /// Propose a new owner for the wallet, who must accept the transfer to become the owner
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+        owner::require_owners_auth(
+            &env,
+            "transfer_ownership",
+            (new_owner.clone(),).into_val(&env),
+        );
+        owner::write_pending_owner(&env, &new_owner)
+    }
+
+    /// Accept a pending ownership transfer of the wallet
+    pub fn accept_ownership(env: Env) {
+        owner::accept_ownership(&env)
+    }
+
+    /// Add a co-owner to the wallet
+    pub fn add_owner(env: Env, new_owner: Address) {
+        owner::require_owners_auth(&env, "add_owner", (new_owner.clone(),).into_val(&env));
+        owner::add_owner(&env, &new_owner)
+    }
+
+    /// Remove a co-owner from the wallet
+    pub fn remove_owner(env: Env, owner: Address) {
+        owner::require_owners_auth(&env, "remove_owner", (owner.clone(),).into_val(&env));
+        owner::remove_owner(&env, &owner)
+    }
+
+    /// Set the number of owners that must approve a sensitive operation
+    pub fn set_threshold(env: Env, threshold: u32) {
+        owner::require_owners_auth(&env, "set_threshold", (threshold,).into_val(&env));
+        owner::write_threshold(&env, threshold)
+    }
+
+    /// Approve the next execution of a sensitive operation, identified by its function name and arguments
+    pub fn approve(env: Env, signer: Address, operation: Symbol, args: Vec<RawVal>) {
+        owner::approve(&env, &signer, &operation, &args)
+    }
+
+    /// Get the owner followed by the co-owners of the wallet
+    pub fn get_owners(env: Env) -> Vec<Address> {
+        owner::read_owners(&env)
+    }
+
+    /// Get the number of owners that must approve a sensitive operation
+    pub fn get_threshold(env: Env) -> u32 {
+        owner::read_threshold(&env)
+    }
+
+    /// Set the guardians that can recover the wallet and the number of them required to do it
+    pub fn set_guardians(env: Env, guardians: Vec<Address>, threshold: u32) {
+        owner::require_owners_auth(
+            &env,
+            "set_guardians",
+            (guardians.clone(), threshold).into_val(&env),
+        );
+        recovery::write_guardians(&env, &guardians, threshold)
+    }
+
+    /// Get the guardians of the wallet
+    pub fn get_guardians(env: Env) -> Guardians {
+        recovery::read_guardians(&env)
+    }
+
+    /// Start the recovery of the wallet in favor of a new owner, counting the approval of the guardian
+    pub fn initiate_recovery(env: Env, guardian: Address, new_owner: Address) {
+        recovery::initiate_recovery(&env, &guardian, &new_owner)
+    }
+
+    /// Approve the recovery of the wallet in progress
+    pub fn approve_recovery(env: Env, guardian: Address) {
+        recovery::approve_recovery(&env, &guardian)
+    }
+
+    /// Complete the recovery of the wallet, the new owner becomes the only owner once the timelock elapsed
+    pub fn complete_recovery(env: Env) {
+        recovery::complete_recovery(&env)
+    }
+
+    /// Cancel the recovery of the wallet in progress
+    pub fn cancel_recovery(env: Env) {
+        owner::read_owner(&env).require_auth();
+        recovery::cancel_recovery(&env)
+    }
+
+    /// Get the recovery of the wallet in progress
+    pub fn get_recovery(env: Env) -> Recovery {
+        recovery::read_recovery(&env)
+    }
+
+    /// Add organizations to the ACL, optionally authorized only until an expiration date
+    pub fn add_organization(env: Env, org_id: Bytes, expiration_date: OptionalTimestamp) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "add_organization",
+            (org_id.clone(), expiration_date.clone()).into_val(&env),
+        );
+        access_control_list::add_organization(&env, &org_id, &expiration_date);
+        events::add_organization(&env, &owner::read_owner(&env), &org_id)
     }
 
     /// Remove organizations from the ACL
     pub fn remove_organization(env: Env, org_id: Bytes) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "remove_organization",
+            (org_id.clone(),).into_val(&env),
+        );
+        access_control_list::remove_organization(&env, &org_id);
+        events::remove_organization(&env, &owner::read_owner(&env), &org_id)
+    }
+
+    /// Add several organizations to the ACL at once, optionally authorized only until an expiration date
+    pub fn add_organizations(env: Env, org_ids: Vec<Bytes>, expiration_date: OptionalTimestamp) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "add_organizations",
+            (org_ids.clone(), expiration_date.clone()).into_val(&env),
+        );
+        access_control_list::add_organizations(&env, &org_ids, &expiration_date);
+        let owner = owner::read_owner(&env);
+        for org_id in org_ids.iter() {
+            events::add_organization(&env, &owner, &org_id.unwrap())
+        }
+    }
+
+    /// Remove several organizations from the ACL at once
+    pub fn remove_organizations(env: Env, org_ids: Vec<Bytes>) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "remove_organizations",
+            (org_ids.clone(),).into_val(&env),
+        );
+        access_control_list::remove_organizations(&env, &org_ids);
+        let owner = owner::read_owner(&env);
+        for org_id in org_ids.iter() {
+            events::remove_organization(&env, &owner, &org_id.unwrap())
+        }
+    }
+
+    /// Set the permissions of an organization in the ACL
+    pub fn set_organization_permissions(env: Env, org_id: Bytes, permissions: Vec<Permission>) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "set_organization_permissions",
+            (org_id.clone(), permissions.clone()).into_val(&env),
+        );
+        access_control_list::set_organization_permissions(&env, &org_id, &permissions)
+    }
+
+    /// Suspend an organization of the ACL, keeping its entry while it can't act over the wallet
+    pub fn suspend_organization(env: Env, org_id: Bytes) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "suspend_organization",
+            (org_id.clone(),).into_val(&env),
+        );
+        access_control_list::set_organization_suspended(&env, &org_id, true);
+        events::suspend_organization(&env, &owner::read_owner(&env), &org_id)
+    }
+
+    /// Resume a suspended organization of the ACL
+    pub fn resume_organization(env: Env, org_id: Bytes) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "resume_organization",
+            (org_id.clone(),).into_val(&env),
+        );
+        access_control_list::set_organization_suspended(&env, &org_id, false);
+        events::resume_organization(&env, &owner::read_owner(&env), &org_id)
+    }
+
+    /// Delegate the ACL administration to an admin contract, e.g. an employer or a custodial service
+    pub fn set_acl_admin(env: Env, admin: Address) {
+        owner::require_owners_auth(&env, "set_acl_admin", (admin.clone(),).into_val(&env));
+        access_control_list::write_acl_admin(&env, &admin)
+    }
+
+    /// Revoke the delegation of the ACL administration, the owner can revoke it at any time
+    pub fn remove_acl_admin(env: Env) {
         owner::read_owner(&env).require_auth();
-        access_control_list::remove_organization(&env, &org_id)
+        access_control_list::remove_acl_admin(&env)
+    }
+
+    /// Get the address the ACL administration is delegated to
+    pub fn get_acl_admin(env: Env) -> Address {
+        access_control_list::read_acl_admin(&env)
     }
 
-    /// Deposit a `Chaincert` to the wallet
+    /// Add an organization to the ACL on behalf of the owner, by the delegated admin
+    pub fn admin_add_organization(
+        env: Env,
+        admin: Address,
+        org_id: Bytes,
+        expiration_date: OptionalTimestamp,
+    ) {
+        state::check_active(&env);
+        access_control_list::check_acl_admin(&env, &admin);
+        access_control_list::add_organization(&env, &org_id, &expiration_date);
+        events::add_organization(&env, &owner::read_owner(&env), &org_id)
+    }
+
+    /// Remove an organization from the ACL on behalf of the owner, by the delegated admin
+    pub fn admin_remove_organization(env: Env, admin: Address, org_id: Bytes) {
+        state::check_active(&env);
+        access_control_list::check_acl_admin(&env, &admin);
+        access_control_list::remove_organization(&env, &org_id);
+        events::remove_organization(&env, &owner::read_owner(&env), &org_id)
+    }
+
+    /// Set the permissions of an organization in the ACL on behalf of the owner, by the delegated admin
+    pub fn admin_set_org_permissions(
+        env: Env,
+        admin: Address,
+        org_id: Bytes,
+        permissions: Vec<Permission>,
+    ) {
+        state::check_active(&env);
+        access_control_list::check_acl_admin(&env, &admin);
+        access_control_list::set_organization_permissions(&env, &org_id, &permissions)
+    }
+
+    /// Set the descriptive information of an organization in the ACL
+    pub fn set_organization_info(env: Env, org_id: Bytes, info: OrgInfo) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "set_organization_info",
+            (org_id.clone(), info.clone()).into_val(&env),
+        );
+        access_control_list::set_organization_info(&env, &org_id, &info)
+    }
+
+    /// Set the address that signs the endorsements on behalf of an organization in the ACL
+    pub fn set_organization_endorser(env: Env, org_id: Bytes, endorser: Address) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "set_organization_endorser",
+            (org_id.clone(), endorser.clone()).into_val(&env),
+        );
+        access_control_list::set_organization_endorser(&env, &org_id, &endorser)
+    }
+
+    /// Limit the number of `Chaincerts` each organization can deposit within a time window in seconds
+    pub fn set_deposit_rate_limit(env: Env, max_deposits: u32, window: u64) {
+        owner::require_owners_auth(
+            &env,
+            "set_deposit_rate_limit",
+            (max_deposits, window).into_val(&env),
+        );
+        rate_limit::write_rate_limit(&env, max_deposits, window)
+    }
+
+    /// Remove the limit of deposits per organization
+    pub fn remove_deposit_rate_limit(env: Env) {
+        owner::require_owners_auth(&env, "remove_deposit_rate_limit", Vec::new(&env));
+        rate_limit::remove_rate_limit(&env)
+    }
+
+    /// Set the maximum number of `Chaincerts` the wallet can store
+    pub fn set_capacity(env: Env, capacity: u32) {
+        owner::require_owners_auth(&env, "set_capacity", (capacity,).into_val(&env));
+        chaincert::write_capacity(&env, capacity)
+    }
+
+    /// Remove the maximum number of `Chaincerts` the wallet can store
+    pub fn remove_capacity(env: Env) {
+        owner::require_owners_auth(&env, "remove_capacity", Vec::new(&env));
+        chaincert::remove_capacity(&env)
+    }
+
+    /// Set whether the deposited `Chaincerts` must be accepted by the owner before being stored in the wallet. The
+    /// distributors see an offered `Chaincert` as deposited, e.g. `certs_governance` doesn't distribute it again when
+    /// the owner rejects it
+    pub fn set_acceptance_required(env: Env, required: bool) {
+        owner::require_owners_auth(&env, "set_acceptance_required", (required,).into_val(&env));
+        pending::write_acceptance_required(&env, required)
+    }
+
+    /// Set the seconds an offered `Chaincert` can be accepted, expired offers are discarded
+    pub fn set_offer_deadline(env: Env, deadline: u64) {
+        owner::require_owners_auth(&env, "set_offer_deadline", (deadline,).into_val(&env));
+        pending::write_offer_deadline(&env, deadline)
+    }
+
+    /// Set the seconds an expired `Chaincert` is still considered valid by `is_chaincert_valid`, zero disables it
+    pub fn set_grace_period(env: Env, grace_period: u64) {
+        owner::require_owners_auth(&env, "set_grace_period", (grace_period,).into_val(&env));
+        chaincert::write_grace_period(&env, grace_period)
+    }
+
+    /// Register a hook contract notified (best-effort) after a `Chaincert` is deposited or an offered one is accepted
+    pub fn set_hook(env: Env, hook: Address) {
+        owner::require_owners_auth(&env, "set_hook", (hook.clone(),).into_val(&env));
+        hook::write_hook(&env, &hook)
+    }
+
+    /// Remove the hook contract of the wallet
+    pub fn remove_hook(env: Env) {
+        owner::require_owners_auth(&env, "remove_hook", Vec::new(&env));
+        hook::remove_hook(&env)
+    }
+
+    /// Deposit a `Chaincert` to the wallet, or offer it to the owner when the wallet requires acceptance
     pub fn deposit_chaincert(
         env: Env,
         chaincert_id: Bytes,
         cid: Bytes,
+        content_hash: BytesN<32>,
         distributor_contract: Address,
         org_id: Bytes,
         distribution_date: u64,
-        expiration_date: OptionU64,
+        expiration_date: OptionalTimestamp,
+        valid_from: OptionalTimestamp,
+        cert_type: Bytes,
+        transferable: bool,
     ) {
-        access_control_list::check_access_control_list(&env, &org_id);
+        state::check_active(&env);
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Deposit);
         distributor_contract.require_auth();
-        chaincert::deposit_chaincert(
+        cid::check_cid(&env, &cid);
+        rate_limit::record_deposit(&env, &org_id);
+        let chaincert = Chaincert::new(
             &env,
-            chaincert_id,
             cid,
+            content_hash,
             distributor_contract,
             org_id,
             distribution_date,
             expiration_date,
+            valid_from,
+            cert_type,
+            transferable,
+            false,
+            false,
+        );
+        deposit_or_offer(&env, &chaincert_id, &chaincert)
+    }
+
+    /// Transfer a transferable `Chaincert` to another wallet, removing it from this wallet. The distributor contract
+    /// must approve the destination wallet to receive it beforehand
+    pub fn transfer_chaincert(env: Env, chaincert_id: Bytes, destination_wallet: Address) {
+        state::check_active(&env);
+        owner::require_owners_auth(
+            &env,
+            "transfer_chaincert",
+            (chaincert_id.clone(), destination_wallet.clone()).into_val(&env),
+        );
+        let destination_id = match destination_wallet.contract_id() {
+            Some(destination_id) => destination_id,
+            None => panic_with_error!(&env, ContractError::InvalidDestinationWallet),
+        };
+        let chaincert = chaincert::take_transferable(&env, &chaincert_id);
+        WalletClient::new(&env, &destination_id).receive_chaincert(
+            &env.current_contract_address(),
+            &chaincert_id,
+            &chaincert,
+        );
+        events::transfer_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &chaincert.org_id,
+            &chaincert_id,
+            &destination_wallet,
+        )
+    }
+
+    /// Allow the wallet to receive a transferable `Chaincert` from another wallet, only the distributor contract and
+    /// organization that issued it can approve it. The approval is consumed by `receive_chaincert`
+    pub fn approve_transfer(
+        env: Env,
+        chaincert_id: Bytes,
+        source_wallet: Address,
+        content_hash: BytesN<32>,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        state::check_active(&env);
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Deposit);
+        distributor_contract.require_auth();
+        transfer::approve_transfer(
+            &env,
+            &chaincert_id,
+            &TransferApproval {
+                source_wallet,
+                distributor_contract,
+                org_id,
+                content_hash,
+            },
+        )
+    }
+
+    /// Receive a transferable `Chaincert` from another wallet, the organization that issued it must be in the ACL and
+    /// its distributor contract must have approved the transfer with `approve_transfer`
+    pub fn receive_chaincert(
+        env: Env,
+        source_wallet: Address,
+        chaincert_id: Bytes,
+        chaincert: Chaincert,
+    ) {
+        state::check_active(&env);
+        access_control_list::check_access_control_list(
+            &env,
+            &chaincert.org_id,
+            Permission::Deposit,
+        );
+        source_wallet.require_auth();
+        transfer::consume_approval(&env, &source_wallet, &chaincert_id, &chaincert);
+        if !chaincert.transferable {
+            panic_with_error!(&env, ContractError::ChaincertNotTransferable)
+        }
+        cid::check_cid(&env, &chaincert.cid);
+        rate_limit::record_deposit(&env, &chaincert.org_id);
+        let mut chaincert = chaincert;
+        chaincert.tags = Vec::new(&env);
+        deposit_or_offer(&env, &chaincert_id, &chaincert)
+    }
+
+    /// Accept a `Chaincert` offered to the wallet, storing it along with the deposited ones
+    pub fn accept_chaincert(env: Env, chaincert_id: Bytes) {
+        state::check_active(&env);
+        let owner = owner::read_owner(&env);
+        owner.require_auth();
+        let chaincert = pending::accept_chaincert(&env, &chaincert_id);
+        events::deposit_chaincert(
+            &env,
+            &owner,
+            &chaincert.org_id,
+            &chaincert_id,
+            &chaincert.distributor_contract,
+        );
+        hook::notify_deposit(&env, &chaincert_id, &chaincert.org_id)
+    }
+
+    /// Get the `Chaincerts` offered to the wallet that can still be accepted
+    pub fn get_pending_chaincerts(env: Env) -> Vec<PendingChaincert> {
+        owner::read_owner(&env).require_auth();
+        pending::get_pending_chaincerts(&env)
+    }
+
+    /// Reject a `Chaincert` offered to the wallet, discarding it
+    pub fn reject_chaincert(env: Env, chaincert_id: Bytes) {
+        let owner = owner::read_owner(&env);
+        owner.require_auth();
+        let chaincert = pending::reject_chaincert(&env, &chaincert_id);
+        events::reject_chaincert(&env, &owner, &chaincert.org_id, &chaincert_id)
+    }
+
+    /// Replace the cid of a `Chaincert`, only the distributor contract and organization that issued it can update it
+    pub fn update_chaincert_cid(
+        env: Env,
+        chaincert_id: Bytes,
+        new_cid: Bytes,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Update);
+        distributor_contract.require_auth();
+        cid::check_cid(&env, &new_cid);
+        chaincert::update_chaincert_cid(
+            &env,
+            &chaincert_id,
+            new_cid.clone(),
+            &distributor_contract,
+            &org_id,
+        );
+        events::update_chaincert_cid(
+            &env,
+            &owner::read_owner(&env),
+            &org_id,
+            &chaincert_id,
+            &new_cid,
+        )
+    }
+
+    /// Attach an additional document to a `Chaincert`, only the distributor contract and organization that issued it can attach documents
+    pub fn add_attachment(
+        env: Env,
+        chaincert_id: Bytes,
+        cid: Bytes,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Update);
+        distributor_contract.require_auth();
+        cid::check_cid(&env, &cid);
+        chaincert::add_attachment(&env, &chaincert_id, &cid, &distributor_contract, &org_id)
+    }
+
+    /// Remove an attached document from a `Chaincert`, only the distributor contract and organization that issued it can remove documents
+    pub fn remove_attachment(
+        env: Env,
+        chaincert_id: Bytes,
+        cid: Bytes,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Update);
+        distributor_contract.require_auth();
+        chaincert::remove_attachment(&env, &chaincert_id, &cid, &distributor_contract, &org_id)
+    }
+
+    /// Set the attributes of a `Chaincert`, only the distributor contract and organization that issued it can set them
+    pub fn set_chaincert_attributes(
+        env: Env,
+        chaincert_id: Bytes,
+        attributes: Map<Bytes, Bytes>,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Update);
+        distributor_contract.require_auth();
+        chaincert::set_attributes(
+            &env,
+            &chaincert_id,
+            &attributes,
+            &distributor_contract,
+            &org_id,
+        )
+    }
+
+    /// Endorse a `Chaincert` distributed by another organization of the ACL, signed by the endorser set for the
+    /// organization
+    pub fn endorse_chaincert(env: Env, chaincert_id: Bytes, endorser: Address, org_id: Bytes) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Endorse);
+        access_control_list::check_endorser(&env, &org_id, &endorser);
+        endorsement::endorse(&env, &chaincert_id, &endorser, &org_id);
+        events::endorse_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &org_id,
+            &chaincert_id,
+            &endorser,
         )
     }
 
@@ -62,21 +688,407 @@ impl Wallet {
         chaincert_id: Bytes,
         distributor_contract: Address,
         org_id: Bytes,
+        reason: RevocationReason,
     ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Revoke);
         distributor_contract.require_auth();
-        chaincert::revoke_chaincert(&env, &chaincert_id, &distributor_contract, &org_id);
+        chaincert::revoke_chaincert(&env, &chaincert_id, &distributor_contract, &org_id, reason);
+        events::revoke_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &org_id,
+            &chaincert_id,
+            &distributor_contract,
+        )
+    }
+
+    /// Reinstate a revoked `Chaincert`, only the distributor contract and organization that issued it can reinstate it
+    pub fn reinstate_chaincert(
+        env: Env,
+        chaincert_id: Bytes,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Revoke);
+        distributor_contract.require_auth();
+        chaincert::reinstate_chaincert(&env, &chaincert_id, &distributor_contract, &org_id);
+        events::reinstate_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &org_id,
+            &chaincert_id,
+            &distributor_contract,
+        )
+    }
+
+    /// Attest a `Chaincert` imported from a snapshot, only the distributor contract and organization that issued it can
+    /// attest it. Restored `Chaincerts` are not valid until attested
+    pub fn attest_chaincert(
+        env: Env,
+        chaincert_id: Bytes,
+        distributor_contract: Address,
+        org_id: Bytes,
+    ) {
+        access_control_list::check_access_control_list(&env, &org_id, Permission::Update);
+        distributor_contract.require_auth();
+        chaincert::attest_chaincert(&env, &chaincert_id, &distributor_contract, &org_id);
+        events::attest_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &org_id,
+            &chaincert_id,
+            &distributor_contract,
+        )
+    }
+
+    /// Withdraw a `Chaincert` held in the wallet, independently of the issuer revocation
+    pub fn withdraw_chaincert(env: Env, chaincert_id: Bytes) {
+        owner::require_owners_auth(
+            &env,
+            "withdraw_chaincert",
+            (chaincert_id.clone(),).into_val(&env),
+        );
+        let chaincert = chaincert::withdraw_chaincert(&env, &chaincert_id);
+        events::withdraw_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &chaincert.org_id,
+            &chaincert_id,
+        )
+    }
+
+    /// Mark a revoked `Chaincert` as disputed, letting verifiers know the owner contests the revocation
+    pub fn dispute_revocation(env: Env, chaincert_id: Bytes, reason: Bytes) {
+        let owner = owner::read_owner(&env);
+        owner.require_auth();
+        let chaincert = chaincert::dispute_revocation(&env, &chaincert_id, &reason);
+        events::dispute_revocation(&env, &owner, &chaincert.org_id, &chaincert_id, &reason)
+    }
+
+    /// Remove a revoked, withdrawn or expired `Chaincert` from the wallet storage
+    pub fn remove_chaincert(env: Env, chaincert_id: Bytes) {
+        owner::require_owners_auth(
+            &env,
+            "remove_chaincert",
+            (chaincert_id.clone(),).into_val(&env),
+        );
+        let chaincert = chaincert::remove_chaincert(&env, &chaincert_id);
+        events::remove_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &chaincert.org_id,
+            &chaincert_id,
+        )
+    }
+
+    /// Remove a `Chaincert` revoked and expired for more than 30 days, anyone can sweep it and get the bounty if set
+    pub fn sweep(env: Env, sweeper: Address, chaincert_id: Bytes) {
+        sweeper.require_auth();
+        let chaincert = chaincert::sweep_chaincert(&env, &chaincert_id);
+        sweep::pay_bounty(&env, &sweeper);
+        events::remove_chaincert(
+            &env,
+            &owner::read_owner(&env),
+            &chaincert.org_id,
+            &chaincert_id,
+        )
+    }
+
+    /// Set the bounty paid to the sweepers from the balance the wallet holds of the token
+    pub fn set_sweep_bounty(env: Env, token: Address, amount: i128) {
+        owner::require_owners_auth(
+            &env,
+            "set_sweep_bounty",
+            (token.clone(), amount).into_val(&env),
+        );
+        sweep::write_sweep_bounty(&env, &SweepBounty { token, amount })
+    }
+
+    /// Remove the bounty paid to the sweepers
+    pub fn remove_sweep_bounty(env: Env) {
+        owner::require_owners_auth(&env, "remove_sweep_bounty", Vec::new(&env));
+        sweep::remove_sweep_bounty(&env)
+    }
+
+    /// Remove the expired `Chaincerts` stored in the wallet, returning the number of removed `Chaincerts`
+    pub fn purge_expired(env: Env) -> u32 {
+        owner::require_owners_auth(&env, "purge_expired", Vec::new(&env));
+        let owner = owner::read_owner(&env);
+        let purged = chaincert::purge_expired(&env);
+        for entry in purged.iter() {
+            let (chaincert_id, chaincert) = entry.unwrap();
+            events::remove_chaincert(&env, &owner, &chaincert.org_id, &chaincert_id);
+        }
+        purged.len()
+    }
+
+    /// Grant a verifier read access to specific `Chaincerts` until the expiration date
+    pub fn grant_access(
+        env: Env,
+        verifier: Address,
+        chaincert_ids: Vec<Bytes>,
+        expiration_date: u64,
+    ) {
+        state::check_active(&env);
+        owner::read_owner(&env).require_auth();
+        access_grant::grant_access(&env, &verifier, &chaincert_ids, expiration_date)
+    }
+
+    /// Revoke the read access granted to a verifier
+    pub fn revoke_access(env: Env, verifier: Address) {
+        owner::read_owner(&env).require_auth();
+        access_grant::revoke_access(&env, &verifier)
+    }
+
+    /// Get the `Chaincerts` shared with a verifier, while the access grant is not expired
+    pub fn get_shared_chaincerts(env: Env, verifier: Address) -> Vec<Chaincert> {
+        state::check_active(&env);
+        verifier.require_auth();
+        access_grant::get_shared_chaincerts(&env, &verifier)
+    }
+
+    /// Register an ownership challenge identified by a nonce, to be answered by the owner
+    pub fn request_ownership_proof(env: Env, verifier: Address, nonce: Bytes) {
+        verifier.require_auth();
+        ownership_proof::request_ownership_proof(&env, &verifier, &nonce)
+    }
+
+    /// Answer an ownership challenge, proving the owner controls the wallet
+    pub fn prove_ownership(env: Env, nonce: Bytes) {
+        owner::read_owner(&env).require_auth();
+        ownership_proof::prove_ownership(&env, &nonce)
     }
 
-    /// Get the list of the `Chaincerts` stored in the wallet
+    /// Get an ownership challenge and its answer
+    pub fn get_ownership_proof(env: Env, nonce: Bytes) -> OwnershipProof {
+        ownership_proof::get_ownership_proof(&env, &nonce)
+    }
+
+    /// Present `Chaincerts` to a verifier, returning the id of the recorded presentation
+    pub fn create_presentation(
+        env: Env,
+        chaincert_ids: Vec<Bytes>,
+        verifier: Address,
+        nonce: Bytes,
+        expiration_date: u64,
+    ) -> BytesN<32> {
+        state::check_active(&env);
+        owner::read_owner(&env).require_auth();
+        presentation::create_presentation(&env, &chaincert_ids, &verifier, &nonce, expiration_date)
+    }
+
+    /// Check if a presentation was made to the verifier and is not expired, successful verifications are recorded in the audit log
+    pub fn verify_presentation(env: Env, presentation_id: BytesN<32>, verifier: Address) -> bool {
+        state::check_active(&env);
+        verifier.require_auth();
+        presentation::verify_presentation(&env, &presentation_id, &verifier)
+    }
+
+    /// Get a page of the record of the verifiers that consumed `Chaincerts` of the wallet
+    pub fn get_audit_log(env: Env, offset: u32, limit: u32) -> AuditPage {
+        owner::read_owner(&env).require_auth();
+        audit::get_audit_log(&env, offset, limit)
+    }
+
+    /// Get a presentation recorded in the wallet
+    pub fn get_presentation(env: Env, presentation_id: BytesN<32>) -> Presentation {
+        presentation::get_presentation(&env, &presentation_id)
+    }
+
+    /// Get the list of the public `Chaincerts` stored in the wallet, ordered by chaincert_id
     pub fn get_chaincerts(env: Env) -> Vec<Chaincert> {
         chaincert::get_chaincerts(&env)
     }
 
+    /// Get the list of the public `Chaincerts` stored in the wallet along with their chaincert_id
+    pub fn get_chaincert_entries(env: Env) -> Vec<(Bytes, Chaincert)> {
+        chaincert::get_chaincert_entries(&env)
+    }
+
+    /// Get a page of the `Chaincerts` stored in the wallet sorted by the given date, along with the total count.
+    /// Private `Chaincerts` are left out of the page
+    pub fn get_chaincerts_sorted(env: Env, by: SortKey, offset: u32, limit: u32) -> ChaincertPage {
+        chaincert::get_chaincerts_sorted(&env, by, offset, limit)
+    }
+
+    /// Get the list of all the `Chaincerts` stored in the wallet, including the private ones
+    pub fn get_all_chaincerts(env: Env) -> Vec<Chaincert> {
+        owner::read_owner(&env).require_auth();
+        chaincert::get_all_chaincerts(&env)
+    }
+
+    /// Set whether a `Chaincert` is public or private, private `Chaincerts` are only listed to the owner and the verifiers they are shared with
+    pub fn set_chaincert_visibility(env: Env, chaincert_id: Bytes, visibility: Visibility) {
+        owner::read_owner(&env).require_auth();
+        chaincert::set_visibility(&env, &chaincert_id, visibility)
+    }
+
+    /// Add a personal tag to a `Chaincert`, tags are only managed by the owner
+    pub fn add_tag(env: Env, chaincert_id: Bytes, tag: Bytes) {
+        owner::read_owner(&env).require_auth();
+        chaincert::add_tag(&env, &chaincert_id, &tag)
+    }
+
+    /// Remove a personal tag from a `Chaincert`
+    pub fn remove_tag(env: Env, chaincert_id: Bytes, tag: Bytes) {
+        owner::read_owner(&env).require_auth();
+        chaincert::remove_tag(&env, &chaincert_id, &tag)
+    }
+
+    /// Set a private note on a `Chaincert`, an empty note removes it
+    pub fn set_chaincert_note(env: Env, chaincert_id: Bytes, note: Bytes) {
+        owner::read_owner(&env).require_auth();
+        chaincert::set_note(&env, &chaincert_id, &note)
+    }
+
+    /// Get the private note of the owner on a `Chaincert`, empty if not set
+    pub fn get_chaincert_note(env: Env, chaincert_id: Bytes) -> Bytes {
+        owner::read_owner(&env).require_auth();
+        chaincert::get_note(&env, &chaincert_id)
+    }
+
+    /// Get the number of `Chaincerts` stored in the wallet
+    pub fn chaincerts_count(env: Env) -> u32 {
+        chaincert::chaincerts_count(&env)
+    }
+
+    /// Get the number of `Chaincerts` the reader can read grouped by status and by organization, private `Chaincerts`
+    /// are only counted for the owner and the verifiers they are shared with
+    pub fn get_stats(env: Env, reader: Option<Address>) -> WalletStats {
+        chaincert::get_stats(&env, &reader)
+    }
+
+    /// Get a `Chaincert` stored in the wallet by its id, private `Chaincerts` can only be read by the owner and the
+    /// verifiers they are shared with
+    pub fn get_chaincert(env: Env, chaincert_id: Bytes, reader: Option<Address>) -> Chaincert {
+        chaincert::get_readable_chaincert(&env, &chaincert_id, &reader)
+    }
+
+    /// Check if the document fetched for a `Chaincert` matches the content hash committed by the organization
+    pub fn verify_content(
+        env: Env,
+        chaincert_id: Bytes,
+        content_hash: BytesN<32>,
+        reader: Option<Address>,
+    ) -> bool {
+        chaincert::verify_content(&env, &chaincert_id, &content_hash, &reader)
+    }
+
+    /// Get the attributes set by the organization on a `Chaincert`
+    pub fn get_chaincert_attributes(
+        env: Env,
+        chaincert_id: Bytes,
+        reader: Option<Address>,
+    ) -> Map<Bytes, Bytes> {
+        chaincert::get_readable_chaincert(&env, &chaincert_id, &reader).attributes
+    }
+
+    /// Get the endorsements of a `Chaincert`, from the oldest to the most recent
+    pub fn get_endorsements(
+        env: Env,
+        chaincert_id: Bytes,
+        reader: Option<Address>,
+    ) -> Vec<Endorsement> {
+        chaincert::get_readable_chaincert(&env, &chaincert_id, &reader);
+        endorsement::read_endorsements(&env, &chaincert_id)
+    }
+
+    /// Get the changes recorded for a `Chaincert`, from the oldest to the most recent
+    pub fn get_chaincert_history(
+        env: Env,
+        chaincert_id: Bytes,
+        reader: Option<Address>,
+    ) -> Vec<HistoryEntry> {
+        chaincert::get_chaincert_history(&env, &chaincert_id, &reader)
+    }
+
+    /// Check if a `Chaincert` is neither revoked, withdrawn, restored without attestation, expired nor pending its
+    /// valid_from date at the current ledger timestamp, private `Chaincerts` can only be checked by the owner and the
+    /// verifiers they are shared with
+    pub fn is_chaincert_valid(env: Env, chaincert_id: Bytes, reader: Option<Address>) -> bool {
+        chaincert::is_chaincert_valid(&env, &chaincert_id, &reader)
+    }
+
+    /// Get a page of the `Chaincerts` stored in the wallet, ordered by chaincert_id, along with the total count. Private
+    /// `Chaincerts` are left out of the page
+    pub fn get_chaincerts_paginated(env: Env, offset: u32, limit: u32) -> ChaincertPage {
+        chaincert::get_chaincerts_paginated(&env, offset, limit)
+    }
+
+    /// Get the list of the public `Chaincerts` issued by an organization
+    pub fn get_chaincerts_by_org(env: Env, org_id: Bytes) -> Vec<Chaincert> {
+        chaincert::get_chaincerts_by_org(&env, &org_id)
+    }
+
+    /// Get the list of the public `Chaincerts` deposited by a distributor contract
+    pub fn get_chaincerts_by_distributor(
+        env: Env,
+        distributor_contract: Address,
+    ) -> Vec<Chaincert> {
+        chaincert::get_chaincerts_by_distributor(&env, &distributor_contract)
+    }
+
+    /// Get the list of the public `Chaincerts` of the given type, e.g. diplomas, memberships or licenses
+    pub fn get_chaincerts_by_type(env: Env, cert_type: Bytes) -> Vec<Chaincert> {
+        chaincert::get_chaincerts_by_type(&env, &cert_type)
+    }
+
+    /// Get the list of the public `Chaincerts` tagged by the owner with the given tag
+    pub fn get_chaincerts_by_tag(env: Env, tag: Bytes) -> Vec<Chaincert> {
+        chaincert::get_chaincerts_by_tag(&env, &tag)
+    }
+
+    /// Get the list of the public `Chaincerts` with the given status (valid, revoked, withdrawn, expired or not yet valid)
+    pub fn get_chaincerts_by_status(env: Env, status: CertStatus) -> Vec<Chaincert> {
+        chaincert::get_chaincerts_by_status(&env, status)
+    }
+
     /// Get the ACL stored in the wallet
-    pub fn get_access_control_list(env: Env) -> Vec<Bytes> {
+    pub fn get_access_control_list(env: Env) -> Vec<OrgEntry> {
         owner::read_owner(&env).require_auth();
         access_control_list::get_access_control_list(&env)
     }
+
+    /// Get the number of organizations in the ACL stored in the wallet
+    pub fn acl_count(env: Env) -> u32 {
+        access_control_list::acl_count(&env)
+    }
+
+    /// Check if an organization is currently authorized to deposit `Chaincerts` to the wallet
+    pub fn is_organization_authorized(env: Env, org_id: Bytes) -> bool {
+        access_control_list::is_organization_authorized(&env, &org_id)
+    }
+
+    /// Get an organization of the ACL stored in the wallet
+    pub fn get_organization(env: Env, org_id: Bytes) -> OrgEntry {
+        owner::read_owner(&env).require_auth();
+        access_control_list::get_organization(&env, &org_id)
+    }
+}
+
+/// Stores a `Chaincert` in the wallet, or offers it to the owner when the wallet requires acceptance
+fn deposit_or_offer(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    let owner = owner::read_owner(env);
+    if pending::is_acceptance_required(env) {
+        pending::offer_chaincert(env, chaincert_id, chaincert);
+        events::offer_chaincert(
+            env,
+            &owner,
+            &chaincert.org_id,
+            chaincert_id,
+            &chaincert.distributor_contract,
+        );
+        return;
+    }
+    chaincert::deposit_chaincert(env, chaincert_id, chaincert);
+    events::deposit_chaincert(
+        env,
+        &owner,
+        &chaincert.org_id,
+        chaincert_id,
+        &chaincert.distributor_contract,
+    );
+    hook::notify_deposit(env, chaincert_id, &chaincert.org_id)
 }
 
 mod test;
@@ -0,0 +1,44 @@
+//! Module Metadata
+//!
+//! Module responsible of managing the descriptive information of the wallet.
+use soroban_sdk::{contracttype, Bytes, Env};
+
+use crate::storage_types::DataKey;
+
+const METADATA_KEY: DataKey = DataKey::Metadata;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Descriptive information that distinguishes the wallet, empty fields are not set
+pub struct WalletMetadata {
+    /// A label chosen by the owner
+    pub label: Bytes,
+    /// The DID of the wallet owner
+    pub owner_did: Bytes,
+    /// The creation date in Unix Timestamp format
+    pub created_at: u64,
+}
+
+pub(crate) fn has_metadata(env: &Env) -> bool {
+    env.storage().has(&METADATA_KEY)
+}
+
+pub(crate) fn write_metadata(env: &Env, label: Bytes, owner_did: Bytes) {
+    let created_at = match env.storage().get(&METADATA_KEY) {
+        Some(metadata) => {
+            let metadata: WalletMetadata = metadata.unwrap();
+            metadata.created_at
+        }
+        None => env.ledger().timestamp(),
+    };
+    let metadata = WalletMetadata {
+        label,
+        owner_did,
+        created_at,
+    };
+    env.storage().set(&METADATA_KEY, &metadata)
+}
+
+pub(crate) fn read_metadata(env: &Env) -> WalletMetadata {
+    env.storage().get_unchecked(&METADATA_KEY).unwrap()
+}
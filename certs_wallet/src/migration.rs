@@ -0,0 +1,151 @@
+//! Module Migration
+//!
+//! Module responsible of converting the storage of wallets deployed with previous layouts into the
+//! current one, tracking the storage version of the wallet.
+//!
+//! Only released layouts are versioned: wallets deployed with 0.4.x have the initial layout (version 0)
+//! and `migrate` converts them into the layout of 0.5.0 (version 2). The layout of unreleased versions
+//! is not stable and wallets deployed from them are not migrated.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, Map, Vec};
+
+use crate::{
+    access_control_list::{self, OrgEntry},
+    chaincert::{self, Chaincert},
+    error::ContractError,
+    metadata,
+    option::OptionalTimestamp,
+    storage_types::DataKey,
+};
+
+/// The storage version of the current layout, any change of the stored structs after a release must
+/// bump it along with a new migration step
+pub(crate) const STORAGE_VERSION: u32 = 2;
+
+const VERSION_KEY: DataKey = DataKey::StorageVersion;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The `Chaincert` struct stored by wallets with storage version 0
+pub struct LegacyChaincert {
+    pub cid: Bytes,
+    pub distributor_contract: Address,
+    pub org_id: Bytes,
+    pub distribution_date: u64,
+    pub expiration_date: OptionalTimestamp,
+    pub revoked: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The version of the wallet code along with the storage versions, used to target wallets for migration
+pub struct WalletVersion {
+    /// The semantic version of the wallet contract
+    pub contract: Bytes,
+    /// The storage version of the wallet
+    pub storage: u32,
+    /// The storage version of the current layout, the wallet must be migrated when it is greater
+    pub latest_storage: u32,
+}
+
+pub(crate) fn read_wallet_version(env: &Env) -> WalletVersion {
+    WalletVersion {
+        contract: Bytes::from_slice(env, env!("CARGO_PKG_VERSION").as_bytes()),
+        storage: read_version(env),
+        latest_storage: STORAGE_VERSION,
+    }
+}
+
+/// Returns the storage version of the wallet, wallets without a version have the initial layout
+pub(crate) fn read_version(env: &Env) -> u32 {
+    match env.storage().get(&VERSION_KEY) {
+        Some(version) => version.unwrap(),
+        None => 0,
+    }
+}
+
+pub(crate) fn write_version(env: &Env, version: u32) {
+    env.storage().set(&VERSION_KEY, &version)
+}
+
+/// Applies the pending migrations in order, each version can only be migrated once
+pub(crate) fn migrate(env: &Env) {
+    let version = read_version(env);
+    if version >= STORAGE_VERSION {
+        panic_with_error!(env, ContractError::AlreadyMigrated)
+    }
+    if version < 1 {
+        migrate_to_v1(env);
+    }
+    if version < 2 {
+        migrate_to_v2(env);
+    }
+    write_version(env, STORAGE_VERSION)
+}
+
+/// Converts the ACL from a list of org_ids into a map of `OrgEntry`, the `Chaincerts` into the
+/// current struct and sets empty metadata
+fn migrate_to_v1(env: &Env) {
+    if let Some(acl) = env.storage().get(&DataKey::AccessControlList) {
+        let org_ids: Vec<Bytes> = acl.unwrap();
+        let mut access_list: Map<Bytes, OrgEntry> = Map::new(env);
+        for org_id in org_ids.iter() {
+            let org_id = org_id.unwrap();
+            access_list.set(
+                org_id.clone(),
+                OrgEntry::new(env, org_id, OptionalTimestamp::None),
+            );
+        }
+        env.storage().set(&DataKey::AccessControlList, &access_list);
+    }
+    if let Some(chaincerts) = env.storage().get(&DataKey::Chaincerts) {
+        let legacy_chaincerts: Map<Bytes, LegacyChaincert> = chaincerts.unwrap();
+        let mut chaincert_map: Map<Bytes, Chaincert> = Map::new(env);
+        for entry in legacy_chaincerts.iter() {
+            let (chaincert_id, legacy) = entry.unwrap();
+            chaincert_map.set(
+                chaincert_id,
+                Chaincert::new(
+                    env,
+                    legacy.cid,
+                    BytesN::from_array(env, &[0; 32]),
+                    legacy.distributor_contract,
+                    legacy.org_id,
+                    legacy.distribution_date,
+                    legacy.expiration_date,
+                    OptionalTimestamp::None,
+                    Bytes::new(env),
+                    false,
+                    legacy.revoked,
+                    false,
+                ),
+            );
+        }
+        env.storage().set(&DataKey::Chaincerts, &chaincert_map);
+    }
+    if !metadata::has_metadata(env) {
+        metadata::write_metadata(env, Bytes::new(env), Bytes::new(env));
+    }
+}
+
+/// Moves each organization and `Chaincert` of the single maps into their own storage entries, the
+/// `Chaincerts` indexed by chaincert_id and by date
+fn migrate_to_v2(env: &Env) {
+    if let Some(acl) = env.storage().get(&DataKey::AccessControlList) {
+        let access_list: Map<Bytes, OrgEntry> = acl.unwrap();
+        for org in access_list.values().iter() {
+            access_control_list::write_organization(env, &org.unwrap());
+        }
+        access_control_list::write_organization_ids(env, &access_list.keys());
+        env.storage().remove(&DataKey::AccessControlList);
+    }
+    if let Some(chaincerts) = env.storage().get(&DataKey::Chaincerts) {
+        let chaincert_map: Map<Bytes, Chaincert> = chaincerts.unwrap();
+        for entry in chaincert_map.iter() {
+            let (chaincert_id, chaincert) = entry.unwrap();
+            chaincert::write_chaincert(env, &chaincert_id, &chaincert);
+            chaincert::add_to_sorted_ids(env, &chaincert_id, &chaincert);
+        }
+        chaincert::write_chaincert_ids(env, &chaincert_map.keys());
+        env.storage().remove(&DataKey::Chaincerts);
+    }
+}
@@ -2,12 +2,46 @@
 //!
 //! Module to create new Option types if necessary since Rust Option doesn't works when using `contracttype` for now
 //! as well as generics. Replace this logic with generic Option when supported
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, Env};
 
-/// OptU64 basic implementation
+/// An optional address
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum OptionalAddress {
+    None,
+    Some(Address),
+}
+
+/// An optional date in Unix Timestamp format
 #[contracttype]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub enum OptionU64 {
+pub enum OptionalTimestamp {
     None,
     Some(u64),
 }
+
+impl OptionalTimestamp {
+    /// Returns the timestamp, or the given default when it is not set
+    pub fn unwrap_or(&self, default: u64) -> u64 {
+        match self {
+            OptionalTimestamp::Some(timestamp) => *timestamp,
+            OptionalTimestamp::None => default,
+        }
+    }
+
+    /// Whether the timestamp is set and already reached by the ledger
+    pub fn is_expired(&self, env: &Env) -> bool {
+        match self {
+            OptionalTimestamp::Some(timestamp) => *timestamp <= env.ledger().timestamp(),
+            OptionalTimestamp::None => false,
+        }
+    }
+
+    /// Whether the timestamp is set and not yet reached by the ledger
+    pub fn is_future(&self, env: &Env) -> bool {
+        match self {
+            OptionalTimestamp::Some(timestamp) => *timestamp > env.ledger().timestamp(),
+            OptionalTimestamp::None => false,
+        }
+    }
+}
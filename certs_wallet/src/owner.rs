@@ -1,11 +1,16 @@
 //! Module Owner
 //!
-//! Module responsible of managing the wallet owner information.
-use soroban_sdk::{Address, Env};
+//! Module responsible of managing the wallet owner information, including the co-owners and the
+//! signature threshold required for sensitive operations.
+use soroban_sdk::{panic_with_error, vec, xdr::ToXdr, Address, BytesN, Env, RawVal, Symbol, Vec};
 
-use crate::storage_types::DataKey;
+use crate::{error::ContractError, storage_types::DataKey};
 
 const OWNER_KEY: DataKey = DataKey::Owner;
+const PENDING_OWNER_KEY: DataKey = DataKey::PendingOwner;
+const CO_OWNERS_KEY: DataKey = DataKey::CoOwners;
+const THRESHOLD_KEY: DataKey = DataKey::Threshold;
+const APPROVED_OPERATIONS_KEY: DataKey = DataKey::ApprovedOperations;
 
 pub(crate) fn has_owner(env: &Env) -> bool {
     env.storage().has(&OWNER_KEY)
@@ -18,3 +23,169 @@ pub(crate) fn read_owner(env: &Env) -> Address {
 pub(crate) fn write_owner(env: &Env, owner: &Address) {
     env.storage().set(&OWNER_KEY, owner);
 }
+
+pub(crate) fn write_pending_owner(env: &Env, pending_owner: &Address) {
+    env.storage().set(&PENDING_OWNER_KEY, pending_owner);
+}
+
+/// Makes the pending owner the owner of the wallet. When the pending owner is a co-owner, the previous
+/// owner takes its place as co-owner so the number of owners still meets the threshold
+pub(crate) fn accept_ownership(env: &Env) {
+    match env.storage().get(&PENDING_OWNER_KEY) {
+        Some(pending_owner) => {
+            let pending_owner: Address = pending_owner.unwrap();
+            pending_owner.require_auth();
+            let mut co_owners = read_co_owners(env);
+            if let Some(index) = co_owners.first_index_of(&pending_owner) {
+                co_owners.set(index, read_owner(env));
+                write_co_owners(env, &co_owners);
+            }
+            write_owner(env, &pending_owner);
+            env.storage().remove(&PENDING_OWNER_KEY);
+        }
+        None => panic_with_error!(env, ContractError::NoPendingOwner),
+    }
+}
+
+/// Returns the owner followed by the co-owners of the wallet
+pub(crate) fn read_owners(env: &Env) -> Vec<Address> {
+    let mut owners = vec![env, read_owner(env)];
+    owners.append(&read_co_owners(env));
+    owners
+}
+
+pub(crate) fn add_owner(env: &Env, new_owner: &Address) {
+    if is_owner(env, new_owner) {
+        panic_with_error!(env, ContractError::AlreadyOwner)
+    }
+    let mut co_owners = read_co_owners(env);
+    co_owners.push_back(new_owner.clone());
+    write_co_owners(env, &co_owners)
+}
+
+pub(crate) fn remove_owner(env: &Env, owner: &Address) {
+    let mut co_owners = read_co_owners(env);
+    match co_owners.first_index_of(owner) {
+        Some(index) => {
+            co_owners.remove(index);
+            if read_threshold(env) > co_owners.len() + 1 {
+                panic_with_error!(env, ContractError::InvalidThreshold)
+            }
+            write_co_owners(env, &co_owners)
+        }
+        None => panic_with_error!(env, ContractError::OwnerNotFound),
+    }
+}
+
+pub(crate) fn read_threshold(env: &Env) -> u32 {
+    match env.storage().get(&THRESHOLD_KEY) {
+        Some(threshold) => threshold.unwrap(),
+        None => 1,
+    }
+}
+
+pub(crate) fn write_threshold(env: &Env, threshold: u32) {
+    if threshold == 0 || threshold > read_owners(env).len() {
+        panic_with_error!(env, ContractError::InvalidThreshold)
+    }
+    env.storage().set(&THRESHOLD_KEY, &threshold)
+}
+
+/// Records the approval of an owner for the next execution of a sensitive operation with the given arguments
+pub(crate) fn approve(env: &Env, signer: &Address, operation: &Symbol, args: &Vec<RawVal>) {
+    if !is_owner(env, signer) {
+        panic_with_error!(env, ContractError::NotAuthorized)
+    }
+    signer.require_auth();
+    let hash = operation_hash(env, operation, args);
+    let key = DataKey::Approvals(hash.clone());
+    let mut approvals: Vec<Address> = match env.storage().get(&key) {
+        Some(approvals) => approvals.unwrap(),
+        None => {
+            let mut approved_operations = read_approved_operations(env);
+            approved_operations.push_back(hash);
+            env.storage()
+                .set(&APPROVED_OPERATIONS_KEY, &approved_operations);
+            Vec::new(env)
+        }
+    };
+    if !approvals.contains(signer) {
+        approvals.push_back(signer.clone());
+        env.storage().set(&key, &approvals)
+    }
+}
+
+/// Requires the owner authorization to execute the operation, and when the threshold is greater than
+/// one, consumes the approvals recorded for the operation and its arguments, where only the approvals
+/// of the current owners count towards the threshold
+pub(crate) fn require_owners_auth(env: &Env, operation: &str, args: Vec<RawVal>) {
+    read_owner(env).require_auth();
+    let threshold = read_threshold(env);
+    if threshold <= 1 {
+        return;
+    }
+    let hash = operation_hash(env, &Symbol::new(env, operation), &args);
+    let key = DataKey::Approvals(hash.clone());
+    let approvals: Vec<Address> = match env.storage().get(&key) {
+        Some(approvals) => approvals.unwrap(),
+        None => Vec::new(env),
+    };
+    let owners = read_owners(env);
+    let mut count = 0;
+    for approval in approvals.iter() {
+        if owners.contains(approval.unwrap()) {
+            count += 1;
+        }
+    }
+    if count < threshold {
+        panic_with_error!(env, ContractError::NotEnoughApprovals)
+    }
+    env.storage().remove(&key);
+    let mut approved_operations = read_approved_operations(env);
+    if let Some(index) = approved_operations.first_index_of(&hash) {
+        approved_operations.remove(index);
+        env.storage()
+            .set(&APPROVED_OPERATIONS_KEY, &approved_operations)
+    }
+}
+
+/// Makes the address the only owner of the wallet, discarding the co-owners, the threshold, the
+/// pending ownership transfer and the recorded approvals
+pub(crate) fn reset_owners(env: &Env, new_owner: &Address) {
+    write_owner(env, new_owner);
+    env.storage().remove(&CO_OWNERS_KEY);
+    env.storage().remove(&THRESHOLD_KEY);
+    env.storage().remove(&PENDING_OWNER_KEY);
+    for hash in read_approved_operations(env).iter() {
+        env.storage().remove(&DataKey::Approvals(hash.unwrap()));
+    }
+    env.storage().remove(&APPROVED_OPERATIONS_KEY)
+}
+
+/// Identifies a sensitive operation along with its arguments
+fn operation_hash(env: &Env, operation: &Symbol, args: &Vec<RawVal>) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(operation.clone(), args.clone()).to_xdr(env))
+}
+
+fn is_owner(env: &Env, address: &Address) -> bool {
+    read_owners(env).contains(address)
+}
+
+fn read_approved_operations(env: &Env) -> Vec<BytesN<32>> {
+    match env.storage().get(&APPROVED_OPERATIONS_KEY) {
+        Some(approved_operations) => approved_operations.unwrap(),
+        None => Vec::new(env),
+    }
+}
+
+fn read_co_owners(env: &Env) -> Vec<Address> {
+    match env.storage().get(&CO_OWNERS_KEY) {
+        Some(co_owners) => co_owners.unwrap(),
+        None => Vec::new(env),
+    }
+}
+
+fn write_co_owners(env: &Env, co_owners: &Vec<Address>) {
+    env.storage().set(&CO_OWNERS_KEY, co_owners)
+}
@@ -0,0 +1,57 @@
+//! Module OwnershipProof
+//!
+//! Module responsible of the challenge/response flow where a verifier registers a nonce and the owner
+//! answers it, recording on-chain that the wallet owner is live and consented.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env};
+
+use crate::{
+    error::ContractError,
+    option::{OptionalAddress, OptionalTimestamp},
+    owner,
+    storage_types::DataKey,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A challenge registered by a verifier and its answer by the owner
+pub struct OwnershipProof {
+    pub verifier: Address,
+    /// The request date in Unix Timestamp format
+    pub requested_at: u64,
+    /// The owner that answered the challenge, `None` until it is answered
+    pub owner: OptionalAddress,
+    /// The answer date in Unix Timestamp format
+    pub proved_at: OptionalTimestamp,
+}
+
+pub(crate) fn request_ownership_proof(env: &Env, verifier: &Address, nonce: &Bytes) {
+    let key = DataKey::OwnershipProof(nonce.clone());
+    if env.storage().has(&key) {
+        panic_with_error!(env, ContractError::ChallengeAlreadyExists)
+    }
+    let proof = OwnershipProof {
+        verifier: verifier.clone(),
+        requested_at: env.ledger().timestamp(),
+        owner: OptionalAddress::None,
+        proved_at: OptionalTimestamp::None,
+    };
+    env.storage().set(&key, &proof)
+}
+
+pub(crate) fn prove_ownership(env: &Env, nonce: &Bytes) {
+    let mut proof = get_ownership_proof(env, nonce);
+    if proof.proved_at != OptionalTimestamp::None {
+        panic_with_error!(env, ContractError::ChallengeAlreadyProved)
+    }
+    proof.owner = OptionalAddress::Some(owner::read_owner(env));
+    proof.proved_at = OptionalTimestamp::Some(env.ledger().timestamp());
+    env.storage()
+        .set(&DataKey::OwnershipProof(nonce.clone()), &proof)
+}
+
+pub(crate) fn get_ownership_proof(env: &Env, nonce: &Bytes) -> OwnershipProof {
+    match env.storage().get(&DataKey::OwnershipProof(nonce.clone())) {
+        Some(proof) => proof.unwrap(),
+        None => panic_with_error!(env, ContractError::ChallengeNotFound),
+    }
+}
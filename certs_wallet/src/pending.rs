@@ -0,0 +1,148 @@
+//! Module Pending
+//!
+//! Module responsible of managing the `Chaincerts` offered to the wallet when the owner requires
+//! accepting them before they are stored along with the deposited ones.
+use soroban_sdk::{contracttype, panic_with_error, Bytes, Env, Map, Vec};
+
+use crate::{
+    access_control_list::{self, Permission},
+    chaincert::{self, Chaincert},
+    error::ContractError,
+    storage_types::DataKey,
+};
+
+const ACCEPTANCE_REQUIRED_KEY: DataKey = DataKey::AcceptanceRequired;
+const PENDING_CHAINCERTS_KEY: DataKey = DataKey::PendingChaincerts;
+const OFFER_DEADLINE_KEY: DataKey = DataKey::OfferDeadline;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A `Chaincert` offered to the wallet and not yet accepted by the owner
+pub struct PendingChaincert {
+    pub chaincert_id: Bytes,
+    pub chaincert: Chaincert,
+    /// The date in Unix Timestamp format the `Chaincert` was offered
+    pub offered_at: u64,
+}
+
+impl PendingChaincert {
+    fn is_expired(&self, env: &Env) -> bool {
+        match read_offer_deadline(env) {
+            Some(deadline) => self.offered_at.saturating_add(deadline) <= env.ledger().timestamp(),
+            None => false,
+        }
+    }
+}
+
+pub(crate) fn is_acceptance_required(env: &Env) -> bool {
+    match env.storage().get(&ACCEPTANCE_REQUIRED_KEY) {
+        Some(required) => required.unwrap(),
+        None => false,
+    }
+}
+
+pub(crate) fn write_acceptance_required(env: &Env, required: bool) {
+    env.storage().set(&ACCEPTANCE_REQUIRED_KEY, &required)
+}
+
+pub(crate) fn write_offer_deadline(env: &Env, deadline: u64) {
+    env.storage().set(&OFFER_DEADLINE_KEY, &deadline)
+}
+
+/// Offers a `Chaincert` to the wallet, discarding the offers whose deadline elapsed
+pub(crate) fn offer_chaincert(env: &Env, chaincert_id: &Bytes, chaincert: &Chaincert) {
+    if chaincert::find_chaincert(env, chaincert_id).is_some() {
+        panic_with_error!(env, ContractError::ChaincertAlreadyInWallet)
+    }
+    let mut pending_chaincerts = read_pending_chaincerts(env);
+    remove_expired(env, &mut pending_chaincerts);
+    if pending_chaincerts.contains_key(chaincert_id.clone()) {
+        panic_with_error!(env, ContractError::ChaincertAlreadyPending)
+    }
+    pending_chaincerts.set(
+        chaincert_id.clone(),
+        PendingChaincert {
+            chaincert_id: chaincert_id.clone(),
+            chaincert: chaincert.clone(),
+            offered_at: env.ledger().timestamp(),
+        },
+    );
+    write_pending_chaincerts(env, &pending_chaincerts)
+}
+
+/// Moves an offered `Chaincert` into the wallet, returning it. The organization that offered it must
+/// still be allowed to deposit
+pub(crate) fn accept_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    let pending_chaincert = take_pending_chaincert(env, chaincert_id);
+    if pending_chaincert.is_expired(env) {
+        panic_with_error!(env, ContractError::OfferExpired)
+    }
+    access_control_list::check_access_control_list(
+        env,
+        &pending_chaincert.chaincert.org_id,
+        Permission::Deposit,
+    );
+    chaincert::deposit_chaincert(env, chaincert_id, &pending_chaincert.chaincert);
+    pending_chaincert.chaincert
+}
+
+/// Discards an offered `Chaincert` even if its deadline elapsed, returning it
+pub(crate) fn reject_chaincert(env: &Env, chaincert_id: &Bytes) -> Chaincert {
+    take_pending_chaincert(env, chaincert_id).chaincert
+}
+
+/// Returns the offers whose deadline has not elapsed
+pub(crate) fn get_pending_chaincerts(env: &Env) -> Vec<PendingChaincert> {
+    let mut pending: Vec<PendingChaincert> = Vec::new(env);
+    for pending_chaincert in read_pending_chaincerts(env).values().iter() {
+        let pending_chaincert = pending_chaincert.unwrap();
+        if !pending_chaincert.is_expired(env) {
+            let mut pending_chaincert = pending_chaincert;
+            pending_chaincert.chaincert =
+                chaincert::with_expiration(env, pending_chaincert.chaincert);
+            pending.push_back(pending_chaincert);
+        }
+    }
+    pending
+}
+
+/// Removes an offered `Chaincert` along with the offers whose deadline elapsed, returning it
+fn take_pending_chaincert(env: &Env, chaincert_id: &Bytes) -> PendingChaincert {
+    let mut pending_chaincerts = read_pending_chaincerts(env);
+    match pending_chaincerts.get(chaincert_id.clone()) {
+        Some(pending_chaincert) => {
+            pending_chaincerts.remove(chaincert_id.clone());
+            remove_expired(env, &mut pending_chaincerts);
+            write_pending_chaincerts(env, &pending_chaincerts);
+            pending_chaincert.unwrap()
+        }
+        None => panic_with_error!(env, ContractError::PendingChaincertNotFound),
+    }
+}
+
+fn remove_expired(env: &Env, pending_chaincerts: &mut Map<Bytes, PendingChaincert>) {
+    for pending_chaincert in pending_chaincerts.values().iter() {
+        let pending_chaincert = pending_chaincert.unwrap();
+        if pending_chaincert.is_expired(env) {
+            pending_chaincerts.remove(pending_chaincert.chaincert_id);
+        }
+    }
+}
+
+fn read_offer_deadline(env: &Env) -> Option<u64> {
+    env.storage()
+        .get(&OFFER_DEADLINE_KEY)
+        .map(|deadline| deadline.unwrap())
+}
+
+fn read_pending_chaincerts(env: &Env) -> Map<Bytes, PendingChaincert> {
+    match env.storage().get(&PENDING_CHAINCERTS_KEY) {
+        Some(pending_chaincerts) => pending_chaincerts.unwrap(),
+        None => Map::new(env),
+    }
+}
+
+fn write_pending_chaincerts(env: &Env, pending_chaincerts: &Map<Bytes, PendingChaincert>) {
+    env.storage()
+        .set(&PENDING_CHAINCERTS_KEY, pending_chaincerts)
+}
@@ -0,0 +1,91 @@
+//! Module Presentation
+//!
+//! Module responsible of recording the presentations of `Chaincerts` made by the owner to verifiers,
+//! so verifiers can confirm the owner intentionally shared them.
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    audit::{self, AuditAction},
+    chaincert,
+    error::ContractError,
+    storage_types::DataKey,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A record of the `Chaincerts` presented to a verifier
+pub struct Presentation {
+    pub chaincert_ids: Vec<Bytes>,
+    pub verifier: Address,
+    /// A value chosen by the verifier that binds the presentation to its request
+    pub nonce: Bytes,
+    /// The creation date in Unix Timestamp format
+    pub created_at: u64,
+    /// The date in Unix Timestamp format after which the presentation is no longer valid
+    pub expiration_date: u64,
+}
+
+/// Records the presentation identified by the sha256 hash of its content, returning the hash
+pub(crate) fn create_presentation(
+    env: &Env,
+    chaincert_ids: &Vec<Bytes>,
+    verifier: &Address,
+    nonce: &Bytes,
+    expiration_date: u64,
+) -> BytesN<32> {
+    for chaincert_id in chaincert_ids.iter() {
+        chaincert::get_chaincert(env, &chaincert_id.unwrap());
+    }
+    let presentation = Presentation {
+        chaincert_ids: chaincert_ids.clone(),
+        verifier: verifier.clone(),
+        nonce: nonce.clone(),
+        created_at: env.ledger().timestamp(),
+        expiration_date,
+    };
+    let presentation_id = env.crypto().sha256(&presentation.clone().to_xdr(env));
+    let key = DataKey::Presentation(presentation_id.clone());
+    if env.storage().has(&key) {
+        panic_with_error!(env, ContractError::PresentationAlreadyExists)
+    }
+    env.storage().set(&key, &presentation);
+    presentation_id
+}
+
+/// Checks that the presentation was made to the verifier and is not expired, recording successful verifications
+pub(crate) fn verify_presentation(
+    env: &Env,
+    presentation_id: &BytesN<32>,
+    verifier: &Address,
+) -> bool {
+    match env
+        .storage()
+        .get(&DataKey::Presentation(presentation_id.clone()))
+    {
+        Some(presentation) => {
+            let presentation: Presentation = presentation.unwrap();
+            let valid = presentation.verifier == verifier.clone()
+                && presentation.expiration_date > env.ledger().timestamp();
+            if valid {
+                audit::record(
+                    env,
+                    verifier,
+                    AuditAction::PresentationVerified,
+                    &presentation.chaincert_ids,
+                );
+            }
+            valid
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn get_presentation(env: &Env, presentation_id: &BytesN<32>) -> Presentation {
+    match env
+        .storage()
+        .get(&DataKey::Presentation(presentation_id.clone()))
+    {
+        Some(presentation) => presentation.unwrap(),
+        None => panic_with_error!(env, ContractError::PresentationNotFound),
+    }
+}
@@ -0,0 +1,71 @@
+//! Module RateLimit
+//!
+//! Module responsible of limiting the number of `Chaincerts` each organization can deposit to the
+//! wallet within a time window.
+use soroban_sdk::{contracttype, panic_with_error, Bytes, Env};
+
+use crate::{error::ContractError, storage_types::DataKey};
+
+const RATE_LIMIT_KEY: DataKey = DataKey::DepositRateLimit;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The maximum number of deposits an organization can make within a time window
+pub struct RateLimit {
+    pub max_deposits: u32,
+    /// The duration of the window in seconds
+    pub window: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The deposits made by an organization within its current window
+pub struct DepositWindow {
+    /// The start date of the window in Unix Timestamp format
+    pub started_at: u64,
+    pub deposits: u32,
+}
+
+pub(crate) fn write_rate_limit(env: &Env, max_deposits: u32, window: u64) {
+    let rate_limit = RateLimit {
+        max_deposits,
+        window,
+    };
+    env.storage().set(&RATE_LIMIT_KEY, &rate_limit)
+}
+
+pub(crate) fn remove_rate_limit(env: &Env) {
+    env.storage().remove(&RATE_LIMIT_KEY)
+}
+
+/// Counts a deposit of the organization, failing when it exceeds the rate limit of the wallet
+pub(crate) fn record_deposit(env: &Env, org_id: &Bytes) {
+    let rate_limit: RateLimit = match env.storage().get(&RATE_LIMIT_KEY) {
+        Some(rate_limit) => rate_limit.unwrap(),
+        None => return,
+    };
+    let key = DataKey::DepositWindow(org_id.clone());
+    let now = env.ledger().timestamp();
+    let mut deposit_window = match env.storage().get(&key) {
+        Some(deposit_window) => {
+            let deposit_window: DepositWindow = deposit_window.unwrap();
+            if now >= deposit_window.started_at.saturating_add(rate_limit.window) {
+                DepositWindow {
+                    started_at: now,
+                    deposits: 0,
+                }
+            } else {
+                deposit_window
+            }
+        }
+        None => DepositWindow {
+            started_at: now,
+            deposits: 0,
+        },
+    };
+    if deposit_window.deposits >= rate_limit.max_deposits {
+        panic_with_error!(env, ContractError::RateLimitExceeded)
+    }
+    deposit_window.deposits += 1;
+    env.storage().set(&key, &deposit_window)
+}
@@ -0,0 +1,116 @@
+//! Module Recovery
+//!
+//! Module responsible of the social recovery of the wallet, where a quorum of guardians nominated by
+//! the owner can rotate the owner after a timelock.
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, Vec};
+
+use crate::{error::ContractError, owner, storage_types::DataKey};
+
+const GUARDIANS_KEY: DataKey = DataKey::Guardians;
+const RECOVERY_KEY: DataKey = DataKey::Recovery;
+
+/// Time in seconds that must elapse between the start of a recovery and its completion
+const RECOVERY_TIMELOCK: u64 = 604800;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The guardians of the wallet and the number of them required to recover it
+pub struct Guardians {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A recovery in progress that rotates the owner of the wallet
+pub struct Recovery {
+    pub new_owner: Address,
+    /// The guardians that approved the recovery
+    pub approvals: Vec<Address>,
+    /// The start date in Unix Timestamp format
+    pub initiated_at: u64,
+}
+
+pub(crate) fn write_guardians(env: &Env, guardians: &Vec<Address>, threshold: u32) {
+    if threshold == 0 || threshold > guardians.len() {
+        panic_with_error!(env, ContractError::InvalidThreshold)
+    }
+    let guardians = Guardians {
+        guardians: guardians.clone(),
+        threshold,
+    };
+    env.storage().set(&GUARDIANS_KEY, &guardians)
+}
+
+pub(crate) fn read_guardians(env: &Env) -> Guardians {
+    match env.storage().get(&GUARDIANS_KEY) {
+        Some(guardians) => guardians.unwrap(),
+        None => Guardians {
+            guardians: Vec::new(env),
+            threshold: 0,
+        },
+    }
+}
+
+pub(crate) fn initiate_recovery(env: &Env, guardian: &Address, new_owner: &Address) {
+    check_guardian(env, guardian);
+    if env.storage().has(&RECOVERY_KEY) {
+        panic_with_error!(env, ContractError::RecoveryInProgress)
+    }
+    let recovery = Recovery {
+        new_owner: new_owner.clone(),
+        approvals: vec![env, guardian.clone()],
+        initiated_at: env.ledger().timestamp(),
+    };
+    env.storage().set(&RECOVERY_KEY, &recovery)
+}
+
+pub(crate) fn approve_recovery(env: &Env, guardian: &Address) {
+    check_guardian(env, guardian);
+    let mut recovery = read_recovery(env);
+    if !recovery.approvals.contains(guardian) {
+        recovery.approvals.push_back(guardian.clone());
+        env.storage().set(&RECOVERY_KEY, &recovery)
+    }
+}
+
+/// Rotates the owner once the recovery is approved by enough of the current guardians and the timelock
+/// elapsed, the new owner becomes the only owner of the wallet
+pub(crate) fn complete_recovery(env: &Env) {
+    let recovery = read_recovery(env);
+    let guardians = read_guardians(env);
+    let mut count = 0;
+    for approval in recovery.approvals.iter() {
+        if guardians.guardians.contains(approval.unwrap()) {
+            count += 1;
+        }
+    }
+    if count < guardians.threshold {
+        panic_with_error!(env, ContractError::NotEnoughApprovals)
+    }
+    if env.ledger().timestamp() < recovery.initiated_at + RECOVERY_TIMELOCK {
+        panic_with_error!(env, ContractError::RecoveryTimelocked)
+    }
+    recovery.new_owner.require_auth();
+    owner::reset_owners(env, &recovery.new_owner);
+    env.storage().remove(&RECOVERY_KEY)
+}
+
+pub(crate) fn cancel_recovery(env: &Env) {
+    read_recovery(env);
+    env.storage().remove(&RECOVERY_KEY)
+}
+
+pub(crate) fn read_recovery(env: &Env) -> Recovery {
+    match env.storage().get(&RECOVERY_KEY) {
+        Some(recovery) => recovery.unwrap(),
+        None => panic_with_error!(env, ContractError::NoRecoveryInProgress),
+    }
+}
+
+fn check_guardian(env: &Env, guardian: &Address) {
+    if !read_guardians(env).guardians.contains(guardian) {
+        panic_with_error!(env, ContractError::NotAuthorized)
+    }
+    guardian.require_auth();
+}
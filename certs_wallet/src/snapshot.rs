@@ -0,0 +1,70 @@
+//! Module Snapshot
+//!
+//! Module responsible of exporting the whole content of the wallet in a single value, suitable for
+//! off-chain backups and for seeding a new wallet instance.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Map, Vec};
+
+use crate::{
+    access_control_list::{self, OrgEntry},
+    chaincert::{self, Chaincert},
+    error::ContractError,
+    metadata::{self, WalletMetadata},
+    migration::{self, WalletVersion},
+    owner,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The content of the wallet at the ledger the snapshot was exported
+pub struct WalletSnapshot {
+    pub owner: Address,
+    pub acl: Vec<OrgEntry>,
+    /// All the `Chaincerts` stored in the wallet, including the private ones, identified by chaincert_id
+    pub chaincerts: Map<Bytes, Chaincert>,
+    pub metadata: WalletMetadata,
+    pub version: WalletVersion,
+    /// The ledger timestamp of the export in Unix Timestamp format
+    pub exported_at: u64,
+}
+
+pub(crate) fn export_snapshot(env: &Env) -> WalletSnapshot {
+    WalletSnapshot {
+        owner: owner::read_owner(env),
+        acl: access_control_list::read_entries(env),
+        chaincerts: chaincert::read_entries(env),
+        metadata: metadata::read_metadata(env),
+        version: migration::read_wallet_version(env),
+        exported_at: env.ledger().timestamp(),
+    }
+}
+
+/// Repopulates a fresh wallet with the organizations, `Chaincerts` and metadata of the snapshot, every
+/// `Chaincert` must be issued by an organization of the snapshot ACL
+pub(crate) fn import_snapshot(env: &Env, snapshot: &WalletSnapshot) {
+    if access_control_list::acl_count(env) > 0 || chaincert::chaincerts_count(env) > 0 {
+        panic_with_error!(env, ContractError::WalletNotEmpty)
+    }
+    let mut org_ids: Vec<Bytes> = Vec::new(env);
+    for org in snapshot.acl.iter() {
+        let org = org.unwrap();
+        match org_ids.binary_search(&org.org_id) {
+            Ok(_) => panic_with_error!(env, ContractError::AlreadyInACL),
+            Err(index) => org_ids.insert(index, org.org_id.clone()),
+        }
+        access_control_list::write_organization(env, &org);
+    }
+    if !org_ids.is_empty() {
+        access_control_list::write_organization_ids(env, &org_ids);
+    }
+    for chaincert in snapshot.chaincerts.values().iter() {
+        if !org_ids.contains(chaincert.unwrap().org_id) {
+            panic_with_error!(env, ContractError::OrganizationNotFound)
+        }
+    }
+    chaincert::restore_chaincerts(env, &snapshot.chaincerts);
+    metadata::write_metadata(
+        env,
+        snapshot.metadata.label.clone(),
+        snapshot.metadata.owner_did.clone(),
+    )
+}
@@ -0,0 +1,46 @@
+//! Module State
+//!
+//! Module responsible of managing the lifecycle state of the wallet.
+use soroban_sdk::{contracttype, panic_with_error, Env};
+
+use crate::{error::ContractError, storage_types::DataKey};
+
+const STATE_KEY: DataKey = DataKey::State;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The lifecycle state of the wallet
+pub enum WalletState {
+    Active,
+    /// Deposits, ACL changes and sharing of `Chaincerts` are blocked, e.g. when the owner suspects a key compromise
+    Frozen,
+    /// The wallet is terminated, it no longer accepts `Chaincerts` and its storage can be cleaned up
+    Closed,
+}
+
+pub(crate) fn read_state(env: &Env) -> WalletState {
+    match env.storage().get(&STATE_KEY) {
+        Some(state) => state.unwrap(),
+        None => WalletState::Active,
+    }
+}
+
+pub(crate) fn write_state(env: &Env, state: WalletState) {
+    env.storage().set(&STATE_KEY, &state)
+}
+
+/// Checks that the wallet is neither frozen nor closed
+pub(crate) fn check_active(env: &Env) {
+    match read_state(env) {
+        WalletState::Active => (),
+        WalletState::Frozen => panic_with_error!(env, ContractError::WalletFrozen),
+        WalletState::Closed => panic_with_error!(env, ContractError::WalletClosed),
+    }
+}
+
+/// Checks that the wallet is not closed, since closing is permanent
+pub(crate) fn check_not_closed(env: &Env) {
+    if read_state(env) == WalletState::Closed {
+        panic_with_error!(env, ContractError::WalletClosed)
+    }
+}
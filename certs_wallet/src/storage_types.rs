@@ -1,14 +1,85 @@
 //! Module StorageTypes
 //!
 //! Module that defines the set of keys that can be used to access and store data within the contract.
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN};
+
+use crate::chaincert::SortKey;
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Owner,
-    /// Access Control List
+    /// The address proposed as new owner, pending to accept the ownership transfer
+    PendingOwner,
+    /// The addresses that share the ownership of the wallet with the owner `Vec<Address>`
+    CoOwners,
+    /// The number of owners that must approve a sensitive operation `u32`
+    Threshold,
+    /// The owners that approved the next execution of an operation, keyed by the hash of the operation and its arguments `Vec<Address>`
+    Approvals(BytesN<32>),
+    /// The hashes of the operations with recorded approvals `Vec<BytesN<32>>`
+    ApprovedOperations,
+    /// The guardians that can recover the wallet and their threshold `Guardians`
+    Guardians,
+    /// The recovery of the wallet in progress `Recovery`
+    Recovery,
+    /// The descriptive information of the wallet `WalletMetadata`
+    Metadata,
+    /// The lifecycle state of the wallet `WalletState`
+    State,
+    /// The version of the storage layout used by the wallet `u32`
+    StorageVersion,
+    /// The address of the `did_contract` that holds the DID document of the owner `Address`
+    DIDContract,
+    /// The contract notified about the Chaincerts stored in the wallet `Address`
+    Hook,
+    /// A list that stored the authorized org_ids up to storage version 0 `Vec<Bytes>`, and a map of them
+    /// identified by org_id up to storage version 1 `Map<Bytes, OrgEntry>`
     AccessControlList,
-    /// A map that stores the Chaincerts, identified by a chaincert_id `Map<Bytes, Chaincert>`
+    /// The ids of the organizations authorized in the ACL, ordered by org_id `Vec<Bytes>`
+    OrganizationIds,
+    /// The address the ACL administration is delegated to by the owner `Address`
+    AclAdmin,
+    /// The maximum number of deposits per organization within a time window `RateLimit`
+    DepositRateLimit,
+    /// The deposits made by an organization in its current window, identified by org_id `DepositWindow`
+    DepositWindow(Bytes),
+    /// A map that stored the Chaincerts up to storage version 1, identified by a chaincert_id `Map<Bytes, Chaincert>`
     Chaincerts,
+    /// The ids of the Chaincerts stored in the wallet, ordered by chaincert_id `Vec<Bytes>`
+    ChaincertIds,
+    /// The ids of the Chaincerts stored in the wallet along with their date, ordered by the date `Vec<(u64, Bytes)>`
+    SortedChaincertIds(SortKey),
+    /// Whether the deposited Chaincerts must be accepted by the owner `bool`
+    AcceptanceRequired,
+    /// The Chaincerts offered to the wallet and not yet accepted, identified by chaincert_id `Map<Bytes, PendingChaincert>`
+    PendingChaincerts,
+    /// The seconds an offered Chaincert can be accepted `u64`
+    OfferDeadline,
+    /// The maximum number of Chaincerts the wallet can store `u32`
+    Capacity,
+    /// The seconds an expired Chaincert is still considered valid by `is_chaincert_valid` `u64`
+    GracePeriod,
+    /// The bounty paid to whoever sweeps a dead Chaincert `SweepBounty`
+    SweepBounty,
+    /// A Chaincert stored in the wallet, identified by its chaincert_id `Chaincert`
+    Chaincert(Bytes),
+    /// An organization authorized in the ACL, identified by its org_id `OrgEntry`
+    Organization(Bytes),
+    /// The bounded list of changes of a Chaincert, identified by its chaincert_id `Vec<HistoryEntry>`
+    ChaincertHistory(Bytes),
+    /// The endorsements of a Chaincert by other organizations, identified by its chaincert_id `Vec<Endorsement>`
+    Endorsements(Bytes),
+    /// The private note of the owner on a Chaincert, identified by its chaincert_id `Bytes`
+    ChaincertNote(Bytes),
+    /// The Chaincerts shared with a verifier, identified by the verifier address `AccessGrant`
+    AccessGrant(Address),
+    /// The bounded record of the verifiers that consumed Chaincerts of the wallet `Vec<AuditEntry>`
+    AuditLog,
+    /// A presentation of Chaincerts to a verifier, identified by the hash of its content `Presentation`
+    Presentation(BytesN<32>),
+    /// An ownership challenge registered by a verifier, identified by its nonce `OwnershipProof`
+    OwnershipProof(Bytes),
+    /// The approval to receive a transferable Chaincert from another wallet, identified by its chaincert_id `TransferApproval`
+    TransferApproval(Bytes),
 }
@@ -0,0 +1,47 @@
+//! Module Sweep
+//!
+//! Module responsible of letting anyone remove the dead `Chaincerts` of the wallet, optionally paying
+//! the sweeper a bounty from the token balance funded by the owner.
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+use crate::storage_types::DataKey;
+
+const SWEEP_BOUNTY_KEY: DataKey = DataKey::SweepBounty;
+
+#[allow(dead_code)]
+#[contractclient(name = "TokenClient")]
+/// The subset of the token interface used to pay the bounties
+pub trait Token {
+    fn xfer(env: Env, from: Address, to: Address, amount: i128);
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The bounty paid to the sweepers from the balance the wallet holds of the token
+pub struct SweepBounty {
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn write_sweep_bounty(env: &Env, bounty: &SweepBounty) {
+    env.storage().set(&SWEEP_BOUNTY_KEY, bounty)
+}
+
+pub(crate) fn remove_sweep_bounty(env: &Env) {
+    env.storage().remove(&SWEEP_BOUNTY_KEY)
+}
+
+/// Pays the bounty to the sweeper, sweeping still succeeds when the wallet can't pay it
+pub(crate) fn pay_bounty(env: &Env, sweeper: &Address) {
+    let bounty: SweepBounty = match env.storage().get(&SWEEP_BOUNTY_KEY) {
+        Some(bounty) => bounty.unwrap(),
+        None => return,
+    };
+    if let Some(token_id) = bounty.token.contract_id() {
+        let _ = TokenClient::new(env, &token_id).try_xfer(
+            &env.current_contract_address(),
+            sweeper,
+            &bounty.amount,
+        );
+    }
+}
@@ -1,11 +1,170 @@
 #![cfg(test)]
+extern crate std;
 
-use crate::{option::OptionU64, Wallet, WalletClient};
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, IntoVal, Vec};
+use crate::{
+    access_control_list::{OrgInfo, Permission},
+    audit::AuditAction,
+    chaincert::{CertStatus, Chaincert, RevocationReason, SortKey, Visibility},
+    history::HistoryAction,
+    migration::LegacyChaincert,
+    option::{OptionalAddress, OptionalTimestamp},
+    pending::PendingChaincert,
+    state::WalletState,
+    storage_types::DataKey,
+    Wallet, WalletClient,
+};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Events, Ledger},
+    vec,
+    xdr::{AccountId, PublicKey, ScAddress, ScVal, Uint256},
+    Address, Bytes, BytesN, Env, IntoVal, Map, RawVal, Symbol, TryFromVal, Vec,
+};
+
+const CONTENT_HASH: [u8; 32] = [1; 32];
+
+mod did_contract {
+    use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+    const CONTROLLER: Symbol = Symbol::short("CTRL");
+
+    pub struct DIDContract;
+
+    #[contractimpl]
+    impl DIDContract {
+        pub fn initialize(env: Env, controller: Address) {
+            env.storage().set(&CONTROLLER, &controller)
+        }
+
+        pub fn is_controller(env: Env, address: Address) -> bool {
+            let controller: Address = env.storage().get_unchecked(&CONTROLLER).unwrap();
+            controller == address
+        }
+    }
+}
+
+mod hook_contract {
+    use soroban_sdk::{contractimpl, Address, Bytes, Env, Symbol, Vec};
+
+    const NOTIFIED: Symbol = Symbol::short("NOTIFIED");
+
+    pub struct HookContract;
+
+    #[contractimpl]
+    impl HookContract {
+        pub fn on_deposit(env: Env, _wallet: Address, chaincert_id: Bytes, _org_id: Bytes) {
+            Self::record(&env, chaincert_id)
+        }
+
+        pub fn notified(env: Env) -> Vec<Bytes> {
+            match env.storage().get(&NOTIFIED) {
+                Some(notified) => notified.unwrap(),
+                None => Vec::new(&env),
+            }
+        }
+    }
+
+    impl HookContract {
+        fn record(env: &Env, chaincert_id: Bytes) {
+            let mut notified = Self::notified(env.clone());
+            notified.push_back(chaincert_id);
+            env.storage().set(&NOTIFIED, &notified)
+        }
+    }
+}
+
+mod distributor_contract {
+    use crate::{option::OptionalTimestamp, WalletClient};
+    use soroban_sdk::{contractimpl, Address, Bytes, BytesN, Env};
+
+    pub struct DistributorContract;
+
+    #[contractimpl]
+    impl DistributorContract {
+        pub fn deposit(
+            env: Env,
+            wallet: BytesN<32>,
+            chaincert_id: Bytes,
+            cid: Bytes,
+            content_hash: BytesN<32>,
+            org_id: Bytes,
+        ) {
+            WalletClient::new(&env, &wallet).deposit_chaincert(
+                &chaincert_id,
+                &cid,
+                &content_hash,
+                &env.current_contract_address(),
+                &org_id,
+                &env.ledger().timestamp(),
+                &OptionalTimestamp::None,
+                &OptionalTimestamp::None,
+                &Bytes::new(&env),
+                &true,
+            )
+        }
+
+        pub fn approve_transfer(
+            env: Env,
+            wallet: BytesN<32>,
+            chaincert_id: Bytes,
+            source_wallet: Address,
+            content_hash: BytesN<32>,
+            org_id: Bytes,
+        ) {
+            WalletClient::new(&env, &wallet).approve_transfer(
+                &chaincert_id,
+                &source_wallet,
+                &content_hash,
+                &env.current_contract_address(),
+                &org_id,
+            )
+        }
+    }
+}
+
+mod token_contract {
+    use soroban_sdk::{contractimpl, Address, Env};
+
+    pub struct TokenContract;
+
+    #[contractimpl]
+    impl TokenContract {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().set(&to, &(balance + amount))
+        }
+
+        pub fn xfer(env: Env, from: Address, to: Address, amount: i128) {
+            let from_balance = Self::balance(env.clone(), from.clone());
+            if from_balance < amount {
+                panic!("insufficient balance")
+            }
+            env.storage().set(&from, &(from_balance - amount));
+            Self::mint(env, to, amount)
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            match env.storage().get(&id) {
+                Some(balance) => balance.unwrap(),
+                None => 0,
+            }
+        }
+    }
+}
+
+fn create_did_contract(e: &Env, controller: &Address) -> Address {
+    let contract_id = e.register_contract(None, did_contract::DIDContract {});
+    did_contract::DIDContractClient::new(e, &contract_id).initialize(controller);
+    Address::from_contract_id(e, &contract_id)
+}
 
 fn create_wallet(e: &Env, owner: &Address) -> WalletClient {
     let wallet = WalletClient::new(e, &e.register_contract(None, Wallet {}));
-    wallet.initialize(owner);
+    wallet.initialize(
+        owner,
+        &"Personal wallet".into_val(e),
+        &"did:chaincerts:7dotwpyzo2weqj6oto6liic6".into_val(e),
+    );
     wallet
 }
 
@@ -49,29 +208,41 @@ fn test_successful_execution_of_wallet_capabilities() {
     let test = ChaincertWalletTest::setup();
     let new_chiancert_id = "CHAINCERT2".into_val(&test.env);
 
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(1).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(1).unwrap(),
+        &OptionalTimestamp::None,
+    );
 
     assert_eq!(test.wallet.get_access_control_list().len(), 2);
 
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 
     test.wallet.deposit_chaincert(
         &new_chiancert_id,
         &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
         &1680205831,
-        &OptionU64::None,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 
     assert_eq!(test.wallet.get_chaincerts().len(), 2);
@@ -80,6 +251,7 @@ fn test_successful_execution_of_wallet_capabilities() {
         &test.chaincert_id,
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
+        &RevocationReason::Unspecified,
     );
 
     test.wallet
@@ -91,7 +263,8 @@ fn test_successful_execution_of_wallet_capabilities() {
 #[should_panic(expected = "Status(ContractError(1))")]
 fn test_initialize_an_already_initialized_wallet() {
     let test = ChaincertWalletTest::setup();
-    test.wallet.initialize(&test.owner);
+    test.wallet
+        .initialize(&test.owner, &Bytes::new(&test.env), &Bytes::new(&test.env));
 }
 
 #[test]
@@ -99,10 +272,14 @@ fn test_initialize_an_already_initialized_wallet() {
 fn test_when_adding_an_already_added_org() {
     let test = ChaincertWalletTest::setup();
 
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
 }
 
 #[test]
@@ -117,8 +294,10 @@ fn test_remove_organization_when_not_organizations_already_set() {
 #[should_panic(expected = "Status(ContractError(8))")]
 fn test_remove_organization_when_organization_not_found() {
     let test = ChaincertWalletTest::setup();
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
     test.wallet
         .remove_organization(&test.organizations.get_unchecked(1).unwrap());
 }
@@ -128,16 +307,22 @@ fn test_remove_organization_when_organization_not_found() {
 fn test_deposit_chaincert_when_organization_is_not_in_the_acl() {
     let test = ChaincertWalletTest::setup();
 
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
 
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get(0).unwrap().unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(1).unwrap(),
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 }
 
@@ -149,10 +334,14 @@ fn test_deposit_chaincert_when_no_organizations_in_the_acl() {
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get(0).unwrap().unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(1).unwrap(),
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 }
 
@@ -161,27 +350,39 @@ fn test_deposit_chaincert_when_no_organizations_in_the_acl() {
 fn test_deposit_chaincert_chaincert_is_already_in_the_wallet() {
     let test = ChaincertWalletTest::setup();
 
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(1).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(1).unwrap(),
+        &OptionalTimestamp::None,
+    );
 
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 }
 
@@ -190,12 +391,15 @@ fn test_deposit_chaincert_chaincert_is_already_in_the_wallet() {
 fn test_revoke_chaincert_when_no_chaincerts_in_wallet() {
     let test = ChaincertWalletTest::setup();
 
-    test.wallet
-        .add_organization(&test.organizations.get_unchecked(0).unwrap());
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
     test.wallet.revoke_chaincert(
         &test.chaincert_id,
         &test.distributor_contract,
         &test.organizations.get_unchecked(0).unwrap(),
+        &RevocationReason::Unspecified,
     )
 }
 
@@ -206,18 +410,27 @@ fn test_revoke_chaincert_when_chaincert_not_found() {
     let org1 = test.organizations.get_unchecked(0).unwrap();
     let new_chaincert: Bytes = "CHAINCERT2".into_val(&test.env);
 
-    test.wallet.add_organization(&org1);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get(0).unwrap().unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &org1,
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 
-    test.wallet
-        .revoke_chaincert(&new_chaincert, &test.distributor_contract, &org1);
+    test.wallet.revoke_chaincert(
+        &new_chaincert,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
 }
 
 #[test]
@@ -227,18 +440,27 @@ fn test_revoke_chaincert_when_not_authorized_contract_or_organization() {
     let org1 = test.organizations.get_unchecked(0).unwrap();
     let org2 = test.organizations.get_unchecked(1).unwrap();
 
-    test.wallet.add_organization(&org1);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
     test.wallet.deposit_chaincert(
         &test.chaincert_id,
         &test.cids.get(0).unwrap().unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
         &test.distributor_contract,
         &org1,
         &1680105831,
-        &OptionU64::Some(1711662757),
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
     );
 
-    test.wallet
-        .revoke_chaincert(&test.chaincert_id, &test.distributor_contract, &org2);
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org2,
+        &RevocationReason::Unspecified,
+    );
 }
 
 #[test]
@@ -256,3 +478,4546 @@ fn test_request_acl_when_no_organizations_set() {
 
     test.wallet.get_access_control_list();
 }
+
+#[test]
+fn test_get_chaincerts_paginated_when_no_chaincerts() {
+    let test = ChaincertWalletTest::setup();
+
+    let page = test.wallet.get_chaincerts_paginated(&0, &10);
+    assert_eq!(page.total, 0);
+    assert_eq!(page.chaincerts.len(), 0);
+}
+
+#[test]
+fn test_get_chaincerts_paginated() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_ids: Vec<Bytes> = vec![
+        &test.env,
+        "CHAINCERT1".into_val(&test.env),
+        "CHAINCERT2".into_val(&test.env),
+        "CHAINCERT3".into_val(&test.env),
+    ];
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    for chaincert_id in chaincert_ids.iter() {
+        test.wallet.deposit_chaincert(
+            &chaincert_id.unwrap(),
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1680105831,
+            &OptionalTimestamp::None,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+
+    let first_page = test.wallet.get_chaincerts_paginated(&0, &2);
+    assert_eq!(first_page.total, 3);
+    assert_eq!(first_page.chaincerts.len(), 2);
+
+    let last_page = test.wallet.get_chaincerts_paginated(&2, &2);
+    assert_eq!(last_page.total, 3);
+    assert_eq!(last_page.chaincerts.len(), 1);
+
+    let out_of_range_page = test.wallet.get_chaincerts_paginated(&5, &2);
+    assert_eq!(out_of_range_page.total, 3);
+    assert_eq!(out_of_range_page.chaincerts.len(), 0);
+}
+
+#[test]
+fn test_get_chaincerts_by_org() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    let new_chaincert_id: Bytes = "CHAINCERT2".into_val(&test.env);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .add_organization(&org2, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &new_chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org2,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let org1_chaincerts = test.wallet.get_chaincerts_by_org(&org1);
+    assert_eq!(org1_chaincerts.len(), 1);
+    assert_eq!(org1_chaincerts.get_unchecked(0).unwrap().org_id, org1);
+
+    let unknown_org: Bytes = "ORG3".into_val(&test.env);
+    assert_eq!(test.wallet.get_chaincerts_by_org(&unknown_org).len(), 0);
+}
+
+#[test]
+fn test_get_chaincerts_by_status() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_ids: Vec<Bytes> = vec![
+        &test.env,
+        "CHAINCERT1".into_val(&test.env),
+        "CHAINCERT2".into_val(&test.env),
+        "CHAINCERT3".into_val(&test.env),
+    ];
+    let expiration_dates = vec![
+        &test.env,
+        OptionalTimestamp::None,
+        OptionalTimestamp::Some(1711662757),
+        OptionalTimestamp::Some(1680205831),
+    ];
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    for (chaincert_id, expiration_date) in chaincert_ids.iter().zip(expiration_dates.iter()) {
+        test.wallet.deposit_chaincert(
+            &chaincert_id.unwrap(),
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1680105831,
+            &expiration_date.unwrap(),
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+    test.wallet.revoke_chaincert(
+        &chaincert_ids.get_unchecked(1).unwrap(),
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+
+    let valid_chaincerts = test.wallet.get_chaincerts_by_status(&CertStatus::Valid);
+    assert_eq!(valid_chaincerts.len(), 1);
+    assert_eq!(
+        valid_chaincerts.get_unchecked(0).unwrap().expiration_date,
+        OptionalTimestamp::None
+    );
+
+    let revoked_chaincerts = test.wallet.get_chaincerts_by_status(&CertStatus::Revoked);
+    assert_eq!(revoked_chaincerts.len(), 1);
+    assert!(revoked_chaincerts.get_unchecked(0).unwrap().revoked);
+
+    let expired_chaincerts = test.wallet.get_chaincerts_by_status(&CertStatus::Expired);
+    assert_eq!(expired_chaincerts.len(), 1);
+    assert_eq!(
+        expired_chaincerts.get_unchecked(0).unwrap().expiration_date,
+        OptionalTimestamp::Some(1680205831)
+    );
+}
+
+#[test]
+fn test_get_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert_eq!(chaincert.cid, test.cids.get_unchecked(0).unwrap());
+    assert_eq!(chaincert.distributor_contract, test.distributor_contract);
+    assert_eq!(chaincert.org_id, org1);
+    assert_eq!(chaincert.distribution_date, 1680105831);
+    assert_eq!(
+        chaincert.expiration_date,
+        OptionalTimestamp::Some(1711662757)
+    );
+    assert!(!chaincert.revoked);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(10))")]
+fn test_get_chaincert_when_chaincert_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let new_chaincert_id: Bytes = "CHAINCERT2".into_val(&test.env);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.get_chaincert(&new_chaincert_id, &None);
+}
+
+#[test]
+fn test_is_chaincert_valid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let new_chaincert_id: Bytes = "CHAINCERT2".into_val(&test.env);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &new_chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    assert!(test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+    assert!(!test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert!(test.wallet.is_chaincert_valid(&new_chaincert_id, &None));
+
+    test.wallet.revoke_chaincert(
+        &new_chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert!(!test.wallet.is_chaincert_valid(&new_chaincert_id, &None));
+}
+
+#[test]
+fn test_owner_authorization_on_acl_management() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "add_organization"),
+            (org1.clone(), OptionalTimestamp::None).into_val(&test.env)
+        )]
+    );
+
+    test.wallet.get_access_control_list();
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "get_access_control_list"),
+            ().into_val(&test.env)
+        )]
+    );
+
+    test.wallet.remove_organization(&org1);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "remove_organization"),
+            (org1,).into_val(&test.env)
+        )]
+    );
+}
+
+#[test]
+fn test_distributor_authorization_on_deposit_and_revoke() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let cid = test.cids.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &cid,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.distributor_contract.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "deposit_chaincert"),
+            (
+                test.chaincert_id.clone(),
+                cid,
+                BytesN::from_array(&test.env, &CONTENT_HASH),
+                test.distributor_contract.clone(),
+                org1.clone(),
+                1680105831_u64,
+                OptionalTimestamp::None,
+                OptionalTimestamp::None,
+                Bytes::new(&test.env),
+                false
+            )
+                .into_val(&test.env)
+        )]
+    );
+
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.distributor_contract.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "revoke_chaincert"),
+            (
+                test.chaincert_id.clone(),
+                test.distributor_contract.clone(),
+                org1,
+                RevocationReason::Unspecified
+            )
+                .into_val(&test.env)
+        )]
+    );
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let test = ChaincertWalletTest::setup();
+    let new_owner = Address::random(&test.env);
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet.transfer_ownership(&new_owner);
+    test.wallet.accept_ownership();
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            new_owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "accept_ownership"),
+            ().into_val(&test.env)
+        )]
+    );
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            new_owner,
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "add_organization"),
+            (org1, OptionalTimestamp::None).into_val(&test.env)
+        )]
+    );
+}
+
+#[test]
+fn test_transfer_ownership_to_co_owner() {
+    let test = ChaincertWalletTest::setup();
+    let co_owner = Address::random(&test.env);
+    let operation = Symbol::new(&test.env, "transfer_ownership");
+    let args: Vec<RawVal> = (co_owner.clone(),).into_val(&test.env);
+    test.wallet.add_owner(&co_owner);
+    test.wallet.set_threshold(&2);
+
+    test.wallet.approve(&test.owner, &operation, &args);
+    test.wallet.approve(&co_owner, &operation, &args);
+    test.wallet.transfer_ownership(&co_owner);
+    test.wallet.accept_ownership();
+    assert_eq!(
+        test.wallet.get_owners(),
+        vec![&test.env, co_owner.clone(), test.owner.clone()]
+    );
+    assert_eq!(test.wallet.get_threshold(), 2);
+
+    let operation = Symbol::new(&test.env, "set_threshold");
+    let args: Vec<RawVal> = (1_u32,).into_val(&test.env);
+    test.wallet.approve(&test.owner, &operation, &args);
+    test.wallet.approve(&co_owner, &operation, &args);
+    test.wallet.set_threshold(&1);
+    assert_eq!(test.wallet.get_threshold(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(12))")]
+fn test_accept_ownership_without_pending_transfer() {
+    let test = ChaincertWalletTest::setup();
+    let new_owner = Address::random(&test.env);
+
+    test.wallet.transfer_ownership(&new_owner);
+    test.wallet.accept_ownership();
+    test.wallet.accept_ownership();
+}
+
+#[test]
+fn test_multi_owner_wallet_with_threshold() {
+    let test = ChaincertWalletTest::setup();
+    let co_owner = Address::random(&test.env);
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let operation = Symbol::new(&test.env, "add_organization");
+    let args: Vec<RawVal> = (org1.clone(), OptionalTimestamp::None).into_val(&test.env);
+
+    test.wallet.add_owner(&co_owner);
+    test.wallet.set_threshold(&2);
+    assert_eq!(
+        test.wallet.get_owners(),
+        vec![&test.env, test.owner.clone(), co_owner.clone()]
+    );
+    assert_eq!(test.wallet.get_threshold(), 2);
+
+    test.wallet.approve(&test.owner, &operation, &args);
+    test.wallet.approve(&co_owner, &operation, &args);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            co_owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "approve"),
+            (co_owner, operation, args).into_val(&test.env)
+        )]
+    );
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "add_organization"),
+            (org1, OptionalTimestamp::None).into_val(&test.env)
+        )]
+    );
+    assert_eq!(test.wallet.get_access_control_list().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(16))")]
+fn test_sensitive_operation_without_enough_approvals() {
+    let test = ChaincertWalletTest::setup();
+    let co_owner = Address::random(&test.env);
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let operation = Symbol::new(&test.env, "add_organization");
+    let args: Vec<RawVal> = (org1.clone(), OptionalTimestamp::None).into_val(&test.env);
+
+    test.wallet.add_owner(&co_owner);
+    test.wallet.set_threshold(&2);
+    test.wallet.approve(&co_owner, &operation, &args);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(16))")]
+fn test_sensitive_operation_with_approvals_for_other_arguments() {
+    let test = ChaincertWalletTest::setup();
+    let co_owner = Address::random(&test.env);
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    let operation = Symbol::new(&test.env, "add_organization");
+    let args: Vec<RawVal> = (org1, OptionalTimestamp::None).into_val(&test.env);
+
+    test.wallet.add_owner(&co_owner);
+    test.wallet.set_threshold(&2);
+    test.wallet.approve(&test.owner, &operation, &args);
+    test.wallet.approve(&co_owner, &operation, &args);
+    test.wallet
+        .add_organization(&org2, &OptionalTimestamp::None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(16))")]
+fn test_sensitive_operation_with_approvals_of_removed_owner() {
+    let test = ChaincertWalletTest::setup();
+    let co_owner1 = Address::random(&test.env);
+    let co_owner2 = Address::random(&test.env);
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let operation = Symbol::new(&test.env, "add_organization");
+    let args: Vec<RawVal> = (org1.clone(), OptionalTimestamp::None).into_val(&test.env);
+    let remove_args: Vec<RawVal> = (co_owner1.clone(),).into_val(&test.env);
+
+    test.wallet.add_owner(&co_owner1);
+    test.wallet.add_owner(&co_owner2);
+    test.wallet.set_threshold(&2);
+    test.wallet.approve(&test.owner, &operation, &args);
+    test.wallet.approve(&co_owner1, &operation, &args);
+    test.wallet.approve(
+        &test.owner,
+        &Symbol::new(&test.env, "remove_owner"),
+        &remove_args,
+    );
+    test.wallet.approve(
+        &co_owner2,
+        &Symbol::new(&test.env, "remove_owner"),
+        &remove_args,
+    );
+    test.wallet.remove_owner(&co_owner1);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_approve_when_signer_is_not_an_owner() {
+    let test = ChaincertWalletTest::setup();
+    let stranger = Address::random(&test.env);
+
+    test.wallet.approve(
+        &stranger,
+        &Symbol::new(&test.env, "add_organization"),
+        &Vec::new(&test.env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(13))")]
+fn test_add_owner_when_already_owner() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.add_owner(&test.owner);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(14))")]
+fn test_remove_owner_when_owner_not_found() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.remove_owner(&Address::random(&test.env));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(15))")]
+fn test_set_threshold_above_number_of_owners() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.add_owner(&Address::random(&test.env));
+    test.wallet.set_threshold(&3);
+}
+
+#[test]
+fn test_wallet_events() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    test.wallet.remove_organization(&org1);
+
+    assert_eq!(
+        test.env.events().all(),
+        vec![
+            &test.env,
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("init"), test.owner.clone()).into_val(&test.env),
+                ().into_val(&test.env)
+            ),
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("add_org"), test.owner.clone(), org1.clone()).into_val(&test.env),
+                ().into_val(&test.env)
+            ),
+            (
+                test.wallet.contract_id.clone(),
+                (
+                    Symbol::short("deposit"),
+                    test.owner.clone(),
+                    org1.clone(),
+                    test.chaincert_id.clone()
+                )
+                    .into_val(&test.env),
+                test.distributor_contract.clone().into_val(&test.env)
+            ),
+            (
+                test.wallet.contract_id.clone(),
+                (
+                    Symbol::short("revoke"),
+                    test.owner.clone(),
+                    org1.clone(),
+                    test.chaincert_id.clone()
+                )
+                    .into_val(&test.env),
+                test.distributor_contract.clone().into_val(&test.env)
+            ),
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("rm_org"), test.owner.clone(), org1).into_val(&test.env),
+                ().into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_withdraw_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.withdraw_chaincert(&test.chaincert_id);
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert!(chaincert.withdrawn);
+    assert!(!chaincert.revoked);
+    assert!(!test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_status(&CertStatus::Withdrawn)
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(10))")]
+fn test_withdraw_chaincert_when_chaincert_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let new_chaincert_id: Bytes = "CHAINCERT2".into_val(&test.env);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.withdraw_chaincert(&new_chaincert_id);
+}
+
+#[test]
+fn test_remove_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let new_chaincert_id: Bytes = "CHAINCERT2".into_val(&test.env);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &new_chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    test.wallet.remove_chaincert(&test.chaincert_id);
+    assert_eq!(test.wallet.get_chaincerts().len(), 1);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+    test.wallet.remove_chaincert(&new_chaincert_id);
+    assert_eq!(test.wallet.get_chaincerts().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(17))")]
+fn test_remove_chaincert_when_chaincert_is_valid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.remove_chaincert(&test.chaincert_id);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(17))")]
+fn test_remove_chaincert_when_chaincert_is_not_yet_valid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::Some(1711662757),
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.remove_chaincert(&test.chaincert_id);
+}
+
+#[test]
+fn test_set_organization_permissions() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let permissions = vec![&test.env, Permission::Deposit];
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .set_organization_permissions(&org1, &permissions);
+
+    let acl = test.wallet.get_access_control_list();
+    assert_eq!(acl.get_unchecked(0).unwrap().permissions, permissions);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.get_chaincerts().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_revoke_chaincert_without_revoke_permission() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .set_organization_permissions(&org1, &vec![&test.env, Permission::Deposit]);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(8))")]
+fn test_set_organization_permissions_when_organization_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .set_organization_permissions(&org2, &vec![&test.env, Permission::Deposit]);
+}
+
+#[test]
+fn test_add_organization_with_expiration_date() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::Some(1690000000));
+    assert_eq!(
+        test.wallet
+            .get_access_control_list()
+            .get_unchecked(0)
+            .unwrap()
+            .expiration_date,
+        OptionalTimestamp::Some(1690000000)
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.get_chaincerts().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_deposit_chaincert_when_organization_access_expired() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::Some(1690000000));
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1690000000,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+fn test_bulk_acl_management() {
+    let test = ChaincertWalletTest::setup();
+    let org3: Bytes = "ORG3".into_val(&test.env);
+    let mut org_ids = test.organizations.clone();
+    org_ids.push_back(org3.clone());
+
+    test.wallet
+        .add_organizations(&org_ids, &OptionalTimestamp::None);
+    assert_eq!(test.wallet.get_access_control_list().len(), 3);
+
+    test.wallet.remove_organizations(&test.organizations);
+    let acl = test.wallet.get_access_control_list();
+    assert_eq!(acl.len(), 1);
+    assert_eq!(acl.get_unchecked(0).unwrap().org_id, org3);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(4))")]
+fn test_add_organizations_when_an_organization_is_already_added() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(1).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(8))")]
+fn test_remove_organizations_when_an_organization_is_not_found() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet.remove_organizations(&test.organizations);
+}
+
+#[test]
+fn test_set_organization_info() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let info = OrgInfo {
+        name: "Kommit University".into_val(&test.env),
+        did: "did:chaincerts:7dotwpyzo2weqj6oto6liic6".into_val(&test.env),
+        service_endpoint: "https://kommit.co".into_val(&test.env),
+    };
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(
+        test.wallet.get_organization(&org1).info.name,
+        Bytes::new(&test.env)
+    );
+
+    test.wallet.set_organization_info(&org1, &info);
+    let organization = test.wallet.get_organization(&org1);
+    assert_eq!(organization.org_id, org1);
+    assert_eq!(organization.info, info);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(8))")]
+fn test_get_organization_when_organization_not_found() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    test.wallet
+        .get_organization(&test.organizations.get_unchecked(1).unwrap());
+}
+
+#[test]
+fn test_is_organization_authorized() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+
+    assert!(!test.wallet.is_organization_authorized(&org1));
+
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::Some(1690000000));
+    test.wallet
+        .add_organization(&org2, &OptionalTimestamp::None);
+    assert!(test.wallet.is_organization_authorized(&org1));
+    assert!(test.wallet.is_organization_authorized(&org2));
+
+    test.wallet
+        .set_organization_permissions(&org2, &vec![&test.env, Permission::Revoke]);
+    assert!(!test.wallet.is_organization_authorized(&org2));
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    assert!(!test.wallet.is_organization_authorized(&org1));
+    assert!(!test
+        .wallet
+        .is_organization_authorized(&"ORG3".into_val(&test.env)));
+}
+
+#[test]
+fn test_wallet_metadata() {
+    let env: Env = Default::default();
+    env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    let owner = Address::random(&env);
+    let wallet = create_wallet(&env, &owner);
+
+    let metadata = wallet.get_metadata();
+    assert_eq!(metadata.label, "Personal wallet".into_val(&env));
+    assert_eq!(
+        metadata.owner_did,
+        "did:chaincerts:7dotwpyzo2weqj6oto6liic6".into_val(&env)
+    );
+    assert_eq!(metadata.created_at, 1680105831);
+
+    env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    wallet.update_metadata(&"Work wallet".into_val(&env), &Bytes::new(&env));
+
+    let metadata = wallet.get_metadata();
+    assert_eq!(metadata.label, "Work wallet".into_val(&env));
+    assert_eq!(metadata.owner_did, Bytes::new(&env));
+    assert_eq!(metadata.created_at, 1680105831);
+}
+
+#[test]
+fn test_set_did() {
+    let test = ChaincertWalletTest::setup();
+    let did_contract = create_did_contract(&test.env, &test.owner);
+
+    test.wallet.set_did(&did_contract);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "set_did"),
+            (did_contract.clone(),).into_val(&test.env)
+        )]
+    );
+    assert_eq!(test.wallet.get_did(), did_contract);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(18))")]
+fn test_set_did_when_owner_is_not_controller() {
+    let test = ChaincertWalletTest::setup();
+    let did_contract = create_did_contract(&test.env, &Address::random(&test.env));
+
+    test.wallet.set_did(&did_contract);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(19))")]
+fn test_get_did_when_not_linked() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.get_did();
+}
+
+#[test]
+fn test_migrate_from_legacy_storage() {
+    let env: Env = Default::default();
+    env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    let owner = Address::random(&env);
+    let distributor_contract = Address::random(&env);
+    let contract_id = env.register_contract(None, Wallet {});
+    let wallet = WalletClient::new(&env, &contract_id);
+    let chaincert_id: Bytes = "CHAINCERT1".into_val(&env);
+    let org_id: Bytes = "ORG1".into_val(&env);
+    let legacy_chaincert = LegacyChaincert {
+        cid: "QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMrEC".into_val(&env),
+        distributor_contract: distributor_contract.clone(),
+        org_id: org_id.clone(),
+        distribution_date: 1680105831,
+        expiration_date: OptionalTimestamp::None,
+        revoked: true,
+    };
+
+    env.as_contract(&contract_id, || {
+        env.storage().set(&DataKey::Owner, &owner);
+        env.storage()
+            .set(&DataKey::AccessControlList, &vec![&env, org_id.clone()]);
+        let chaincerts: Map<Bytes, LegacyChaincert> =
+            map![&env, (chaincert_id.clone(), legacy_chaincert)];
+        env.storage().set(&DataKey::Chaincerts, &chaincerts);
+    });
+
+    wallet.migrate();
+
+    let organization = wallet.get_organization(&org_id);
+    assert_eq!(organization.expiration_date, OptionalTimestamp::None);
+    assert_eq!(organization.permissions.len(), 4);
+
+    let chaincert = wallet.get_chaincert(&chaincert_id, &None);
+    assert_eq!(chaincert.distributor_contract, distributor_contract);
+    assert!(chaincert.revoked);
+    assert!(!chaincert.withdrawn);
+
+    let metadata = wallet.get_metadata();
+    assert_eq!(metadata.label, Bytes::new(&env));
+    assert_eq!(metadata.created_at, 1680105831);
+}
+
+#[test]
+fn test_migrate_maps_into_entries() {
+    let test = ChaincertWalletTest::setup();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &test.organizations.get_unchecked(0).unwrap(),
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    let organizations = test.wallet.get_access_control_list();
+
+    test.env.as_contract(&test.wallet.contract_id, || {
+        let mut access_list = Map::new(&test.env);
+        for org in organizations.iter() {
+            let org = org.unwrap();
+            test.env
+                .storage()
+                .remove(&DataKey::Organization(org.org_id.clone()));
+            access_list.set(org.org_id.clone(), org);
+        }
+        test.env
+            .storage()
+            .set(&DataKey::AccessControlList, &access_list);
+        test.env.storage().remove(&DataKey::OrganizationIds);
+        let chaincerts: Map<Bytes, Chaincert> = map![
+            &test.env,
+            (test.chaincert_id.clone(), chaincert.clone()),
+            (chaincert_id2.clone(), chaincert.clone())
+        ];
+        test.env.storage().set(&DataKey::Chaincerts, &chaincerts);
+        test.env.storage().remove(&DataKey::ChaincertIds);
+        test.env
+            .storage()
+            .remove(&DataKey::Chaincert(test.chaincert_id.clone()));
+        test.env.storage().set(&DataKey::StorageVersion, &1_u32);
+    });
+
+    test.wallet.migrate();
+
+    assert_eq!(test.wallet.get_access_control_list(), organizations);
+    assert_eq!(test.wallet.chaincerts_count(), 2);
+    assert_eq!(test.wallet.get_chaincert(&chaincert_id2, &None), chaincert);
+    assert_eq!(
+        test.wallet.get_chaincerts(),
+        vec![&test.env, chaincert.clone(), chaincert]
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_sorted(&SortKey::DistributionDate, &0, &10)
+            .total,
+        2
+    );
+    test.env.as_contract(&test.wallet.contract_id, || {
+        assert!(!test.env.storage().has(&DataKey::Chaincerts));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(20))")]
+fn test_migrate_when_already_migrated() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.migrate();
+}
+
+#[test]
+fn test_count_getters() {
+    let test = ChaincertWalletTest::setup();
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+    assert_eq!(test.wallet.acl_count(), 0);
+
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &test.organizations.get_unchecked(0).unwrap(),
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    assert_eq!(test.wallet.chaincerts_count(), 1);
+    assert_eq!(test.wallet.acl_count(), 2);
+}
+
+#[test]
+fn test_revoke_chaincert_records_reason_and_date() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert_eq!(chaincert.revocation_reason, RevocationReason::Unspecified);
+    assert_eq!(chaincert.revocation_date, OptionalTimestamp::None);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::AffiliationChanged,
+    );
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert!(chaincert.revoked);
+    assert_eq!(
+        chaincert.revocation_reason,
+        RevocationReason::AffiliationChanged
+    );
+    assert_eq!(
+        chaincert.revocation_date,
+        OptionalTimestamp::Some(1690000000)
+    );
+}
+
+#[test]
+fn test_deposit_chaincert_with_cid_v1() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let cid_v1: Bytes =
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &cid_v1,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(
+        test.wallet.get_chaincert(&test.chaincert_id, &None).cid,
+        cid_v1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(21))")]
+fn test_deposit_chaincert_with_invalid_cid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &"QmdtyfTYbVS3K9iYqBPjXxn4mbB7aBvEjYGzYWnzRcMr0".into_val(&test.env),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+fn test_deposit_chaincert_with_valid_from() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::Some(1690000000),
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert!(!test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_status(&CertStatus::NotYetValid)
+            .len(),
+        1
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    assert!(test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_status(&CertStatus::Valid)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_purge_expired() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(test.wallet.purge_expired(), 0);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1690000000),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &chaincert_id2,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.purge_expired(), 0);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    assert_eq!(test.wallet.purge_expired(), 1);
+    assert_eq!(test.wallet.chaincerts_count(), 1);
+    assert!(test.wallet.is_chaincert_valid(&chaincert_id2, &None));
+}
+
+#[test]
+fn test_purge_expired_when_revoked() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1690000000),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    test.wallet
+        .set_chaincert_note(&test.chaincert_id, &"note".into_val(&test.env));
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    assert_eq!(test.wallet.purge_expired(), 1);
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+    assert!(!test.env.as_contract(&test.wallet.contract_id, || test
+        .env
+        .storage()
+        .has(&DataKey::ChaincertNote(test.chaincert_id.clone()))));
+}
+
+#[test]
+fn test_update_chaincert_cid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let cid1 = test.cids.get_unchecked(0).unwrap();
+    let new_cid: Bytes = "QmerTm8dYitCQQSGd33saPFyMWgd2de8KV63KhYUL9hC7S".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &cid1,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.update_chaincert_cid(
+        &test.chaincert_id,
+        &new_cid,
+        &test.distributor_contract,
+        &org1,
+    );
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.distributor_contract.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "update_chaincert_cid"),
+            (
+                test.chaincert_id.clone(),
+                new_cid.clone(),
+                test.distributor_contract.clone(),
+                org1
+            )
+                .into_val(&test.env)
+        )]
+    );
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert_eq!(chaincert.cid, new_cid);
+    assert_eq!(chaincert.previous_cids, vec![&test.env, cid1]);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_update_chaincert_cid_when_not_issuer() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.update_chaincert_cid(
+        &test.chaincert_id,
+        &"QmerTm8dYitCQQSGd33saPFyMWgd2de8KV63KhYUL9hC7S".into_val(&test.env),
+        &test.distributor_contract,
+        &org2,
+    );
+}
+
+#[test]
+fn test_chaincert_history() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let cid1 = test.cids.get_unchecked(0).unwrap();
+    let new_cid: Bytes = "QmerTm8dYitCQQSGd33saPFyMWgd2de8KV63KhYUL9hC7S".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &cid1,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet.update_chaincert_cid(
+        &test.chaincert_id,
+        &new_cid,
+        &test.distributor_contract,
+        &org1,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Superseded,
+    );
+
+    let history = test.wallet.get_chaincert_history(&test.chaincert_id, &None);
+    assert_eq!(history.len(), 3);
+    let deposited = history.get_unchecked(0).unwrap();
+    assert_eq!(deposited.action, HistoryAction::Deposited);
+    assert_eq!(deposited.cid, cid1);
+    assert_eq!(deposited.timestamp, 1680105831);
+    let updated = history.get_unchecked(1).unwrap();
+    assert_eq!(updated.action, HistoryAction::CidUpdated);
+    assert_eq!(updated.cid, new_cid);
+    assert_eq!(
+        history.get_unchecked(2).unwrap().action,
+        HistoryAction::Revoked
+    );
+}
+
+#[test]
+fn test_chaincert_history_is_bounded() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let cid1 = test.cids.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &cid1,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    for _ in 0..20 {
+        test.wallet.update_chaincert_cid(
+            &test.chaincert_id,
+            &cid1,
+            &test.distributor_contract,
+            &org1,
+        );
+    }
+
+    let history = test.wallet.get_chaincert_history(&test.chaincert_id, &None);
+    assert_eq!(history.len(), 20);
+    assert_eq!(
+        history.get_unchecked(0).unwrap().action,
+        HistoryAction::CidUpdated
+    );
+}
+
+#[test]
+fn test_chaincert_attachments() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let transcript: Bytes = "QmerTm8dYitCQQSGd33saPFyMWgd2de8KV63KhYUL9hC7S".into_val(&test.env);
+    let credential: Bytes =
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.add_attachment(
+        &test.chaincert_id,
+        &transcript,
+        &test.distributor_contract,
+        &org1,
+    );
+    test.wallet.add_attachment(
+        &test.chaincert_id,
+        &credential,
+        &test.distributor_contract,
+        &org1,
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert(&test.chaincert_id, &None)
+            .attachments,
+        vec![&test.env, transcript.clone(), credential.clone()]
+    );
+
+    test.wallet.remove_attachment(
+        &test.chaincert_id,
+        &transcript,
+        &test.distributor_contract,
+        &org1,
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert(&test.chaincert_id, &None)
+            .attachments,
+        vec![&test.env, credential]
+    );
+    let history = test.wallet.get_chaincert_history(&test.chaincert_id, &None);
+    let removed = history.get_unchecked(3).unwrap();
+    assert_eq!(removed.action, HistoryAction::AttachmentRemoved);
+    assert_eq!(removed.cid, transcript);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(23))")]
+fn test_remove_attachment_when_attachment_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.remove_attachment(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &test.distributor_contract,
+        &org1,
+    );
+}
+
+#[test]
+fn test_get_chaincerts_by_type() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let diploma: Bytes = "diploma".into_val(&test.env);
+    let membership: Bytes = "membership".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &diploma,
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &membership,
+        &false,
+    );
+
+    let diplomas = test.wallet.get_chaincerts_by_type(&diploma);
+    assert_eq!(diplomas.len(), 1);
+    assert_eq!(diplomas.get_unchecked(0).unwrap().cert_type, diploma);
+    assert_eq!(test.wallet.get_chaincerts_by_type(&membership).len(), 1);
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_type(&"license".into_val(&test.env))
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_get_chaincerts_by_distributor() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let distributor_contract2 = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &distributor_contract2,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let chaincerts = test
+        .wallet
+        .get_chaincerts_by_distributor(&test.distributor_contract);
+    assert_eq!(chaincerts.len(), 1);
+    assert_eq!(
+        chaincerts.get_unchecked(0).unwrap().distributor_contract,
+        test.distributor_contract
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_distributor(&Address::random(&test.env))
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_grant_access_to_verifier() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet.grant_access(
+        &verifier,
+        &vec![&test.env, test.chaincert_id.clone()],
+        &1690000000,
+    );
+
+    let shared = test.wallet.get_shared_chaincerts(&verifier);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            verifier.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "get_shared_chaincerts"),
+            (verifier.clone(),).into_val(&test.env)
+        )]
+    );
+    assert_eq!(
+        shared,
+        vec![
+            &test.env,
+            test.wallet.get_chaincert(&test.chaincert_id, &None)
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_get_shared_chaincerts_when_access_expired() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.grant_access(
+        &verifier,
+        &vec![&test.env, test.chaincert_id.clone()],
+        &1690000000,
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet.get_shared_chaincerts(&verifier);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_get_shared_chaincerts_when_access_revoked() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.grant_access(
+        &verifier,
+        &vec![&test.env, test.chaincert_id.clone()],
+        &1690000000,
+    );
+
+    test.wallet.revoke_access(&verifier);
+    test.wallet.get_shared_chaincerts(&verifier);
+}
+
+#[test]
+fn test_create_presentation() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    let nonce: Bytes = "NONCE1".into_val(&test.env);
+    let chaincert_ids = vec![&test.env, test.chaincert_id.clone()];
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    let presentation_id =
+        test.wallet
+            .create_presentation(&chaincert_ids, &verifier, &nonce, &1690000000);
+
+    let presentation = test.wallet.get_presentation(&presentation_id);
+    assert_eq!(presentation.chaincert_ids, chaincert_ids);
+    assert_eq!(presentation.nonce, nonce);
+    assert_eq!(presentation.created_at, 1680105831);
+    assert!(test.wallet.verify_presentation(&presentation_id, &verifier));
+    assert!(!test
+        .wallet
+        .verify_presentation(&presentation_id, &Address::random(&test.env)));
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    assert!(!test.wallet.verify_presentation(&presentation_id, &verifier));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_is_private_chaincert_valid_when_not_shared() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet
+        .set_chaincert_visibility(&test.chaincert_id, &Visibility::Private);
+    assert!(test
+        .wallet
+        .is_chaincert_valid(&test.chaincert_id, &Some(test.owner.clone())));
+
+    test.wallet.is_chaincert_valid(&test.chaincert_id, &None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_read_shared_chaincert_when_wallet_frozen() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    test.wallet
+        .set_chaincert_visibility(&test.chaincert_id, &Visibility::Private);
+    test.wallet.grant_access(
+        &verifier,
+        &vec![&test.env, test.chaincert_id.clone()],
+        &1690000000,
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert(&test.chaincert_id, &Some(verifier.clone()))
+            .cid,
+        test.cids.get_unchecked(0).unwrap()
+    );
+
+    test.wallet.freeze();
+    test.wallet
+        .get_chaincert(&test.chaincert_id, &Some(verifier));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(28))")]
+fn test_create_presentation_when_wallet_frozen() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    test.wallet.freeze();
+
+    test.wallet.create_presentation(
+        &vec![&test.env, test.chaincert_id.clone()],
+        &verifier,
+        &"NONCE1".into_val(&test.env),
+        &1690000000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(28))")]
+fn test_verify_presentation_when_wallet_frozen() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    let presentation_id = test.wallet.create_presentation(
+        &vec![&test.env, test.chaincert_id.clone()],
+        &verifier,
+        &"NONCE1".into_val(&test.env),
+        &1690000000,
+    );
+    test.wallet.freeze();
+
+    test.wallet.verify_presentation(&presentation_id, &verifier);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(10))")]
+fn test_create_presentation_when_chaincert_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.create_presentation(
+        &vec![&test.env, "CHAINCERT2".into_val(&test.env)],
+        &Address::random(&test.env),
+        &"NONCE1".into_val(&test.env),
+        &1690000000,
+    );
+}
+
+#[test]
+fn test_set_chaincert_visibility() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    let verifier = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &chaincert_id2,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet
+        .set_chaincert_visibility(&test.chaincert_id, &Visibility::Private);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "set_chaincert_visibility"),
+            (test.chaincert_id.clone(), Visibility::Private).into_val(&test.env)
+        )]
+    );
+
+    let private_chaincert = test
+        .wallet
+        .get_chaincert(&test.chaincert_id, &Some(test.owner.clone()));
+    assert_eq!(private_chaincert.visibility, Visibility::Private);
+    assert_eq!(test.wallet.get_stats(&None).total, 1);
+    assert_eq!(test.wallet.get_stats(&Some(test.owner.clone())).total, 2);
+    assert_eq!(
+        test.wallet.get_chaincerts(),
+        vec![&test.env, test.wallet.get_chaincert(&chaincert_id2, &None)]
+    );
+    let page = test.wallet.get_chaincerts_paginated(&0, &10);
+    assert_eq!(page.total, 2);
+    assert_eq!(page.chaincerts.len(), 1);
+    assert_eq!(test.wallet.get_chaincerts_by_org(&org1).len(), 1);
+    assert_eq!(test.wallet.get_all_chaincerts().len(), 2);
+
+    test.wallet.grant_access(
+        &verifier,
+        &vec![&test.env, test.chaincert_id.clone()],
+        &1690000000,
+    );
+    assert_eq!(
+        test.wallet.get_shared_chaincerts(&verifier),
+        vec![&test.env, private_chaincert.clone()]
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert(&test.chaincert_id, &Some(verifier.clone())),
+        private_chaincert
+    );
+    assert!(test.wallet.verify_content(
+        &test.chaincert_id,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &Some(verifier)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_read_private_chaincert_when_not_shared() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet
+        .set_chaincert_visibility(&test.chaincert_id, &Visibility::Private);
+
+    test.wallet
+        .get_chaincert_history(&test.chaincert_id, &Some(Address::random(&test.env)));
+}
+
+#[test]
+fn test_prove_ownership() {
+    let test = ChaincertWalletTest::setup();
+    let verifier = Address::random(&test.env);
+    let nonce: Bytes = "NONCE1".into_val(&test.env);
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet.request_ownership_proof(&verifier, &nonce);
+    let proof = test.wallet.get_ownership_proof(&nonce);
+    assert_eq!(proof.verifier, verifier);
+    assert_eq!(proof.requested_at, 1680105831);
+    assert_eq!(proof.owner, OptionalAddress::None);
+    assert_eq!(proof.proved_at, OptionalTimestamp::None);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105900);
+    test.wallet.prove_ownership(&nonce);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "prove_ownership"),
+            (nonce.clone(),).into_val(&test.env)
+        )]
+    );
+
+    let proof = test.wallet.get_ownership_proof(&nonce);
+    assert_eq!(proof.owner, OptionalAddress::Some(test.owner.clone()));
+    assert_eq!(proof.proved_at, OptionalTimestamp::Some(1680105900));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(27))")]
+fn test_prove_ownership_when_challenge_not_found() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet.prove_ownership(&"NONCE1".into_val(&test.env));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(50))")]
+fn test_prove_ownership_when_already_proved() {
+    let test = ChaincertWalletTest::setup();
+    let nonce: Bytes = "NONCE1".into_val(&test.env);
+    test.wallet
+        .request_ownership_proof(&Address::random(&test.env), &nonce);
+
+    test.wallet.prove_ownership(&nonce);
+    test.wallet.prove_ownership(&nonce);
+}
+
+#[test]
+fn test_freeze_wallet() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    assert_eq!(test.wallet.get_state(), WalletState::Active);
+
+    test.wallet.freeze();
+    assert_eq!(test.wallet.get_state(), WalletState::Frozen);
+
+    test.wallet.unfreeze();
+    assert_eq!(test.wallet.get_state(), WalletState::Active);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    assert_eq!(test.wallet.acl_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(28))")]
+fn test_add_organization_when_wallet_frozen() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.freeze();
+
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(28))")]
+fn test_deposit_chaincert_when_wallet_frozen() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.freeze();
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+fn test_close_wallet() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let successor = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.close_wallet(&Some(successor.clone()));
+    assert_eq!(test.wallet.get_state(), WalletState::Closed);
+    assert_eq!(test.wallet.acl_count(), 1);
+    let events = test.env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &test.env,
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("close"), test.owner.clone()).into_val(&test.env),
+                Some(successor).into_val(&test.env)
+            )
+        ]
+    );
+
+    test.wallet.remove_chaincert(&test.chaincert_id);
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(29))")]
+fn test_deposit_chaincert_when_wallet_closed() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.close_wallet(&None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+fn test_revoke_chaincert_when_wallet_closed() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.close_wallet(&None);
+
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert!(test.wallet.get_chaincert(&test.chaincert_id, &None).revoked);
+    assert!(!test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(29))")]
+fn test_unfreeze_when_wallet_closed() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.close_wallet(&None);
+
+    test.wallet.unfreeze();
+}
+
+#[test]
+fn test_guardian_recovery() {
+    let test = ChaincertWalletTest::setup();
+    let guardian1 = Address::random(&test.env);
+    let guardian2 = Address::random(&test.env);
+    let guardian3 = Address::random(&test.env);
+    let new_owner = Address::random(&test.env);
+    test.wallet.set_guardians(
+        &vec![
+            &test.env,
+            guardian1.clone(),
+            guardian2.clone(),
+            guardian3.clone(),
+        ],
+        &2,
+    );
+    assert_eq!(test.wallet.get_guardians().threshold, 2);
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet.initiate_recovery(&guardian1, &new_owner);
+    test.wallet.approve_recovery(&guardian2);
+    let recovery = test.wallet.get_recovery();
+    assert_eq!(recovery.new_owner, new_owner);
+    assert_eq!(recovery.approvals, vec![&test.env, guardian1, guardian2]);
+    assert_eq!(recovery.initiated_at, 1680105831);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1680105831 + 604800);
+    test.wallet.complete_recovery();
+    assert_eq!(test.wallet.get_owners(), vec![&test.env, new_owner]);
+}
+
+#[test]
+fn test_complete_recovery_resets_owners() {
+    let test = ChaincertWalletTest::setup();
+    let guardian = Address::random(&test.env);
+    let co_owner = Address::random(&test.env);
+    let new_owner = Address::random(&test.env);
+    let operation = Symbol::new(&test.env, "add_organization");
+    let args: Vec<RawVal> = (
+        test.organizations.get_unchecked(0).unwrap(),
+        OptionalTimestamp::None,
+    )
+        .into_val(&test.env);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian.clone()], &1);
+    test.wallet.add_owner(&co_owner);
+    test.wallet.set_threshold(&2);
+    test.wallet.approve(&co_owner, &operation, &args);
+    test.wallet.approve(
+        &test.owner,
+        &Symbol::new(&test.env, "transfer_ownership"),
+        &(co_owner.clone(),).into_val(&test.env),
+    );
+    test.wallet.approve(
+        &co_owner,
+        &Symbol::new(&test.env, "transfer_ownership"),
+        &(co_owner.clone(),).into_val(&test.env),
+    );
+    test.wallet.transfer_ownership(&co_owner);
+
+    test.wallet.initiate_recovery(&guardian, &new_owner);
+    test.env.ledger().with_mut(|li| li.timestamp = 604800);
+    test.wallet.complete_recovery();
+
+    assert_eq!(test.wallet.get_owners(), vec![&test.env, new_owner]);
+    assert_eq!(test.wallet.get_threshold(), 1);
+    test.env.as_contract(&test.wallet.contract_id, || {
+        assert!(!test.env.storage().has(&DataKey::PendingOwner));
+        assert!(!test.env.storage().has(&DataKey::ApprovedOperations));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(16))")]
+fn test_complete_recovery_with_approvals_of_removed_guardian() {
+    let test = ChaincertWalletTest::setup();
+    let guardian1 = Address::random(&test.env);
+    let guardian2 = Address::random(&test.env);
+    let guardian3 = Address::random(&test.env);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian1.clone(), guardian2.clone()], &2);
+    test.wallet
+        .initiate_recovery(&guardian1, &Address::random(&test.env));
+    test.wallet.approve_recovery(&guardian2);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian1, guardian3], &2);
+    test.env.ledger().with_mut(|li| li.timestamp = 604800);
+
+    test.wallet.complete_recovery();
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(32))")]
+fn test_complete_recovery_before_timelock() {
+    let test = ChaincertWalletTest::setup();
+    let guardian = Address::random(&test.env);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian.clone()], &1);
+    test.wallet
+        .initiate_recovery(&guardian, &Address::random(&test.env));
+
+    test.wallet.complete_recovery();
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(16))")]
+fn test_complete_recovery_without_enough_approvals() {
+    let test = ChaincertWalletTest::setup();
+    let guardian1 = Address::random(&test.env);
+    let guardian2 = Address::random(&test.env);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian1.clone(), guardian2], &2);
+    test.wallet
+        .initiate_recovery(&guardian1, &Address::random(&test.env));
+    test.env.ledger().with_mut(|li| li.timestamp = 604800);
+
+    test.wallet.complete_recovery();
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(31))")]
+fn test_cancel_recovery() {
+    let test = ChaincertWalletTest::setup();
+    let guardian = Address::random(&test.env);
+    test.wallet
+        .set_guardians(&vec![&test.env, guardian.clone()], &1);
+    test.wallet
+        .initiate_recovery(&guardian, &Address::random(&test.env));
+
+    test.wallet.cancel_recovery();
+    test.wallet.get_recovery();
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_initiate_recovery_when_not_guardian() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet
+        .set_guardians(&vec![&test.env, Address::random(&test.env)], &1);
+
+    test.wallet
+        .initiate_recovery(&Address::random(&test.env), &Address::random(&test.env));
+}
+
+#[test]
+fn test_deposit_rate_limit() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.set_deposit_rate_limit(&1, &86400);
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org2,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1680105831 + 86400);
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT3".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.chaincerts_count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(33))")]
+fn test_deposit_chaincert_when_rate_limit_exceeded() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.set_deposit_rate_limit(&1, &86400);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(33))")]
+fn test_deposit_chaincert_when_rate_limit_exceeded_with_unbounded_window() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.set_deposit_rate_limit(&1, &u64::MAX);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(34))")]
+fn test_deposit_chaincert_when_wallet_full() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.set_capacity(&1);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.deposit_chaincert(
+        &"CHAINCERT2".into_val(&test.env),
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+}
+
+#[test]
+fn test_remove_capacity() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.set_capacity(&0);
+    test.wallet.remove_capacity();
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.chaincerts_count(), 1);
+}
+
+#[test]
+fn test_delegated_acl_administration() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let admin = Address::random(&test.env);
+    test.wallet.set_acl_admin(&admin);
+    assert_eq!(test.wallet.get_acl_admin(), admin);
+
+    test.wallet
+        .admin_add_organization(&admin, &org1, &OptionalTimestamp::None);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            admin.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "admin_add_organization"),
+            (admin.clone(), org1.clone(), OptionalTimestamp::None).into_val(&test.env)
+        )]
+    );
+    assert!(test.wallet.is_organization_authorized(&org1));
+
+    test.wallet
+        .admin_set_org_permissions(&admin, &org1, &vec![&test.env, Permission::Revoke]);
+    assert!(!test.wallet.is_organization_authorized(&org1));
+
+    test.wallet.admin_remove_organization(&admin, &org1);
+    assert_eq!(test.wallet.acl_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_admin_add_organization_when_delegation_revoked() {
+    let test = ChaincertWalletTest::setup();
+    let admin = Address::random(&test.env);
+    test.wallet.set_acl_admin(&admin);
+    test.wallet.remove_acl_admin();
+
+    test.wallet.admin_add_organization(
+        &admin,
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+}
+
+#[test]
+fn test_hook_notifications() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    let hook_id = test
+        .env
+        .register_contract(None, hook_contract::HookContract {});
+    let hook = hook_contract::HookContractClient::new(&test.env, &hook_id);
+    test.wallet
+        .set_hook(&Address::from_contract_id(&test.env, &hook_id));
+    test.wallet.set_acceptance_required(&true);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(hook.notified().len(), 0);
+
+    test.wallet.accept_chaincert(&test.chaincert_id);
+    assert_eq!(hook.notified(), vec![&test.env, test.chaincert_id.clone()]);
+
+    test.wallet.set_acceptance_required(&false);
+    test.wallet.deposit_chaincert(
+        &chaincert_id2,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(
+        hook.notified(),
+        vec![&test.env, test.chaincert_id.clone(), chaincert_id2.clone()]
+    );
+
+    test.wallet.revoke_chaincert(
+        &chaincert_id2,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert_eq!(hook.notified().len(), 2);
+}
+
+#[test]
+fn test_get_chaincert_entries() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    for chaincert_id in [test.chaincert_id.clone(), chaincert_id2.clone()] {
+        test.wallet.deposit_chaincert(
+            &chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1680105831,
+            &OptionalTimestamp::None,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+    test.wallet
+        .set_chaincert_visibility(&chaincert_id2, &Visibility::Private);
+
+    let entries = test.wallet.get_chaincert_entries();
+    assert_eq!(entries.len(), 1);
+    let (chaincert_id, chaincert) = entries.get_unchecked(0).unwrap();
+    assert_eq!(chaincert_id, test.chaincert_id);
+    assert_eq!(
+        chaincert,
+        test.wallet.get_chaincert(&test.chaincert_id, &None)
+    );
+}
+
+#[test]
+fn test_get_chaincerts_sorted() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    let chaincert_id3: Bytes = "CHAINCERT3".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    for (chaincert_id, distribution_date, expiration_date) in [
+        (
+            test.chaincert_id.clone(),
+            1680105831,
+            OptionalTimestamp::None,
+        ),
+        (
+            chaincert_id2,
+            1680105800,
+            OptionalTimestamp::Some(1780105831),
+        ),
+        (
+            chaincert_id3,
+            1680105900,
+            OptionalTimestamp::Some(1711641600),
+        ),
+    ] {
+        test.wallet.deposit_chaincert(
+            &chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &distribution_date,
+            &expiration_date,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+
+    let by_distribution = test
+        .wallet
+        .get_chaincerts_sorted(&SortKey::DistributionDate, &0, &10)
+        .chaincerts;
+    assert_eq!(by_distribution.len(), 3);
+    assert_eq!(
+        by_distribution.get_unchecked(0).unwrap().distribution_date,
+        1680105800
+    );
+    assert_eq!(
+        by_distribution.get_unchecked(1).unwrap().distribution_date,
+        1680105831
+    );
+    assert_eq!(
+        by_distribution.get_unchecked(2).unwrap().distribution_date,
+        1680105900
+    );
+
+    let by_expiration = test
+        .wallet
+        .get_chaincerts_sorted(&SortKey::ExpirationDate, &0, &10)
+        .chaincerts;
+    assert_eq!(
+        by_expiration.get_unchecked(0).unwrap().expiration_date,
+        OptionalTimestamp::Some(1711641600)
+    );
+    assert_eq!(
+        by_expiration.get_unchecked(1).unwrap().expiration_date,
+        OptionalTimestamp::Some(1780105831)
+    );
+    assert_eq!(
+        by_expiration.get_unchecked(2).unwrap().expiration_date,
+        OptionalTimestamp::None
+    );
+
+    test.wallet.withdraw_chaincert(&test.chaincert_id);
+    test.wallet.remove_chaincert(&test.chaincert_id);
+    let last_page = test
+        .wallet
+        .get_chaincerts_sorted(&SortKey::DistributionDate, &1, &10);
+    assert_eq!(last_page.total, 2);
+    assert_eq!(last_page.chaincerts.len(), 1);
+    assert_eq!(
+        last_page
+            .chaincerts
+            .get_unchecked(0)
+            .unwrap()
+            .distribution_date,
+        1680105900
+    );
+}
+
+#[test]
+fn test_chaincert_attributes() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert_attributes(&test.chaincert_id, &None),
+        Map::new(&test.env)
+    );
+
+    let attributes: Map<Bytes, Bytes> = map![
+        &test.env,
+        ("grade".into_val(&test.env), "A".into_val(&test.env)),
+        ("locale".into_val(&test.env), "en-US".into_val(&test.env))
+    ];
+    test.wallet.set_chaincert_attributes(
+        &test.chaincert_id,
+        &attributes,
+        &test.distributor_contract,
+        &org1,
+    );
+    assert_eq!(
+        test.wallet
+            .get_chaincert_attributes(&test.chaincert_id, &None),
+        attributes
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(36))")]
+fn test_set_chaincert_attributes_when_too_many_attributes() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let mut attributes: Map<Bytes, Bytes> = Map::new(&test.env);
+    for key in 0..11u8 {
+        attributes.set(Bytes::from_array(&test.env, &[key]), Bytes::new(&test.env));
+    }
+    test.wallet.set_chaincert_attributes(
+        &test.chaincert_id,
+        &attributes,
+        &test.distributor_contract,
+        &org1,
+    );
+}
+
+#[test]
+fn test_endorse_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    let endorser = Address::random(&test.env);
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.set_organization_endorser(&org2, &endorser);
+    assert_eq!(
+        test.wallet.get_organization(&org2).endorser,
+        OptionalAddress::Some(endorser.clone())
+    );
+    assert_eq!(
+        test.wallet
+            .get_endorsements(&test.chaincert_id, &None)
+            .len(),
+        0
+    );
+
+    test.wallet
+        .endorse_chaincert(&test.chaincert_id, &endorser, &org2);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            endorser.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "endorse_chaincert"),
+            (test.chaincert_id.clone(), endorser.clone(), org2.clone()).into_val(&test.env),
+        )]
+    );
+
+    let endorsements = test.wallet.get_endorsements(&test.chaincert_id, &None);
+    assert_eq!(endorsements.len(), 1);
+    let endorsement = endorsements.get_unchecked(0).unwrap();
+    assert_eq!(endorsement.org_id, org2);
+    assert_eq!(endorsement.endorser, endorser);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(37))")]
+fn test_endorse_chaincert_when_already_endorsed() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    let endorser = Address::random(&test.env);
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.set_organization_endorser(&org2, &endorser);
+
+    test.wallet
+        .endorse_chaincert(&test.chaincert_id, &endorser, &org2);
+    test.wallet
+        .endorse_chaincert(&test.chaincert_id, &endorser, &org2);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_endorse_own_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet
+        .set_organization_endorser(&org1, &test.distributor_contract);
+
+    test.wallet
+        .endorse_chaincert(&test.chaincert_id, &test.distributor_contract, &org1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_endorse_chaincert_by_other_endorser() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet
+        .set_organization_endorser(&org2, &Address::random(&test.env));
+
+    test.wallet
+        .endorse_chaincert(&test.chaincert_id, &Address::random(&test.env), &org2);
+}
+
+#[test]
+fn test_chaincert_tags() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let work: Bytes = "work".into_val(&test.env);
+    let education: Bytes = "education".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.add_tag(&test.chaincert_id, &work);
+    test.wallet.add_tag(&test.chaincert_id, &education);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "add_tag"),
+            (test.chaincert_id.clone(), education.clone()).into_val(&test.env)
+        )]
+    );
+    assert_eq!(
+        test.wallet.get_chaincert(&test.chaincert_id, &None).tags,
+        vec![&test.env, work.clone(), education.clone()]
+    );
+    assert_eq!(test.wallet.get_chaincerts_by_tag(&work).len(), 1);
+
+    test.wallet.remove_tag(&test.chaincert_id, &work);
+    assert_eq!(test.wallet.get_chaincerts_by_tag(&work).len(), 0);
+    assert_eq!(test.wallet.get_chaincerts_by_tag(&education).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(39))")]
+fn test_remove_tag_when_tag_not_found() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet
+        .remove_tag(&test.chaincert_id, &"work".into_val(&test.env));
+}
+
+#[test]
+fn test_dispute_revocation() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let reason: Bytes = "Revoked before the appeal ended".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::PrivilegeWithdrawn,
+    );
+
+    test.wallet.dispute_revocation(&test.chaincert_id, &reason);
+    let events = test.env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &test.env,
+            (
+                test.wallet.contract_id.clone(),
+                (
+                    Symbol::short("dispute"),
+                    test.owner.clone(),
+                    org1.clone(),
+                    test.chaincert_id.clone()
+                )
+                    .into_val(&test.env),
+                reason.into_val(&test.env)
+            )
+        ]
+    );
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert!(chaincert.revoked);
+    assert!(chaincert.disputed);
+    assert_eq!(chaincert.dispute_reason, reason);
+    let history = test.wallet.get_chaincert_history(&test.chaincert_id, &None);
+    assert_eq!(
+        history.get_unchecked(history.len() - 1).unwrap().action,
+        HistoryAction::Disputed
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(40))")]
+fn test_dispute_revocation_when_chaincert_not_revoked() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet
+        .dispute_revocation(&test.chaincert_id, &Bytes::new(&test.env));
+}
+
+#[test]
+fn test_reinstate_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::IssuedInError,
+    );
+    test.wallet
+        .dispute_revocation(&test.chaincert_id, &"Issued in error".into_val(&test.env));
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet
+        .reinstate_chaincert(&test.chaincert_id, &test.distributor_contract, &org1);
+
+    let chaincert = test.wallet.get_chaincert(&test.chaincert_id, &None);
+    assert!(!chaincert.revoked);
+    assert!(!chaincert.disputed);
+    assert_eq!(chaincert.revocation_date, OptionalTimestamp::None);
+    assert_eq!(
+        chaincert.reinstatement_date,
+        OptionalTimestamp::Some(1690000000)
+    );
+    assert!(test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    let history = test.wallet.get_chaincert_history(&test.chaincert_id, &None);
+    assert_eq!(
+        history.get_unchecked(history.len() - 1).unwrap().action,
+        HistoryAction::Reinstated
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_reinstate_chaincert_when_not_issuer() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+
+    test.wallet
+        .reinstate_chaincert(&test.chaincert_id, &test.distributor_contract, &org2);
+}
+
+#[test]
+fn test_is_chaincert_valid_during_grace_period() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.set_grace_period(&86400);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+    assert!(test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert_eq!(
+        test.wallet
+            .get_chaincerts_by_status(&CertStatus::Expired)
+            .len(),
+        1
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1711662757 + 86400);
+    assert!(!test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+}
+
+#[test]
+fn test_get_stats() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let org2 = test.organizations.get_unchecked(1).unwrap();
+    let stats = test.wallet.get_stats(&None);
+    assert_eq!(stats.total, 0);
+    assert_eq!(stats.by_org, Map::new(&test.env));
+
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    for (chaincert_id, org_id, expiration_date) in [
+        (
+            test.chaincert_id.clone(),
+            org1.clone(),
+            OptionalTimestamp::None,
+        ),
+        (
+            "CHAINCERT2".into_val(&test.env),
+            org1.clone(),
+            OptionalTimestamp::Some(1711662757),
+        ),
+        (
+            "CHAINCERT3".into_val(&test.env),
+            org2.clone(),
+            OptionalTimestamp::None,
+        ),
+    ] {
+        test.wallet.deposit_chaincert(
+            &chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org_id,
+            &1680105831,
+            &expiration_date,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+    test.wallet.revoke_chaincert(
+        &"CHAINCERT3".into_val(&test.env),
+        &test.distributor_contract,
+        &org2,
+        &RevocationReason::Unspecified,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+
+    let stats = test.wallet.get_stats(&None);
+    assert_eq!(stats.total, 3);
+    assert_eq!(stats.valid, 1);
+    assert_eq!(stats.revoked, 1);
+    assert_eq!(stats.expired, 1);
+    assert_eq!(stats.withdrawn, 0);
+    assert_eq!(stats.by_org, map![&test.env, (org1, 2), (org2, 1)]);
+}
+
+#[test]
+fn test_two_phase_deposit() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    test.wallet.set_acceptance_required(&true);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+
+    for chaincert_id in [test.chaincert_id.clone(), chaincert_id2.clone()] {
+        test.wallet.deposit_chaincert(
+            &chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1680105831,
+            &OptionalTimestamp::None,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+
+    test.wallet.accept_chaincert(&test.chaincert_id);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "accept_chaincert"),
+            (test.chaincert_id.clone(),).into_val(&test.env)
+        )]
+    );
+    test.wallet.reject_chaincert(&chaincert_id2);
+
+    let chaincerts = test.wallet.get_chaincerts();
+    assert_eq!(chaincerts.len(), 1);
+    assert_eq!(chaincerts.get_unchecked(0).unwrap().org_id, org1);
+    assert!(test.wallet.is_chaincert_valid(&test.chaincert_id, &None));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(42))")]
+fn test_accept_chaincert_when_not_offered() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.set_acceptance_required(&true);
+
+    test.wallet.accept_chaincert(&test.chaincert_id);
+}
+
+#[test]
+fn test_pending_chaincerts_expire_after_deadline() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet.set_acceptance_required(&true);
+    test.wallet.set_offer_deadline(&86400);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1690000000,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    let pending = test.wallet.get_pending_chaincerts();
+    assert_eq!(pending.len(), 1);
+    let pending_chaincert = pending.get_unchecked(0).unwrap();
+    assert_eq!(pending_chaincert.chaincert_id, test.chaincert_id);
+    assert_eq!(pending_chaincert.offered_at, 1690000000);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1690000000 + 86400);
+    assert_eq!(test.wallet.get_pending_chaincerts().len(), 0);
+
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1690086400,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(test.wallet.get_pending_chaincerts().len(), 1);
+}
+
+#[test]
+fn test_accept_chaincert_removes_expired_offers() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let chaincert_id2: Bytes = "CHAINCERT2".into_val(&test.env);
+    let chaincert_id3: Bytes = "CHAINCERT3".into_val(&test.env);
+    test.wallet.set_acceptance_required(&true);
+    test.wallet.set_offer_deadline(&86400);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.env.ledger().with_mut(|li| li.timestamp = 1690000000);
+    for chaincert_id in [&test.chaincert_id, &chaincert_id2] {
+        test.wallet.deposit_chaincert(
+            chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1690000000,
+            &OptionalTimestamp::None,
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+    }
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1690000000 + 43200);
+    test.wallet.deposit_chaincert(
+        &chaincert_id3,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1690043200,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1690000000 + 86400);
+    test.wallet.accept_chaincert(&chaincert_id3);
+
+    test.env.as_contract(&test.wallet.contract_id, || {
+        let pending_chaincerts: Map<Bytes, PendingChaincert> = test
+            .env
+            .storage()
+            .get_unchecked(&DataKey::PendingChaincerts)
+            .unwrap();
+        assert!(pending_chaincerts.is_empty());
+    });
+    assert_eq!(test.wallet.chaincerts_count(), 1);
+}
+
+#[test]
+fn test_reject_chaincert_when_offer_expired() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet.set_acceptance_required(&true);
+    test.wallet.set_offer_deadline(&86400);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = 86400);
+    test.wallet.reject_chaincert(&test.chaincert_id);
+
+    test.env.as_contract(&test.wallet.contract_id, || {
+        let pending_chaincerts: Map<Bytes, PendingChaincert> = test
+            .env
+            .storage()
+            .get_unchecked(&DataKey::PendingChaincerts)
+            .unwrap();
+        assert!(pending_chaincerts.is_empty());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(46))")]
+fn test_accept_chaincert_when_organization_suspended() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet.set_acceptance_required(&true);
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.suspend_organization(&org1);
+    test.wallet.accept_chaincert(&test.chaincert_id);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(2))")]
+fn test_accept_chaincert_when_organization_removed() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet.set_acceptance_required(&true);
+    test.wallet
+        .add_organizations(&test.organizations, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.remove_organization(&org1);
+    test.wallet.accept_chaincert(&test.chaincert_id);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(43))")]
+fn test_accept_chaincert_when_offer_expired() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet.set_acceptance_required(&true);
+    test.wallet.set_offer_deadline(&86400);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = 86400);
+    test.wallet.accept_chaincert(&test.chaincert_id);
+}
+
+#[test]
+fn test_transfer_chaincert() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let destination_owner = Address::random(&test.env);
+    let destination = create_wallet(&test.env, &destination_owner);
+    destination.add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &"ticket".into_val(&test.env),
+        &true,
+    );
+    test.wallet
+        .add_tag(&test.chaincert_id, &"events".into_val(&test.env));
+
+    destination.approve_transfer(
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &test.wallet.contract_id),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+    );
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.distributor_contract.clone(),
+            destination.contract_id.clone(),
+            Symbol::new(&test.env, "approve_transfer"),
+            (
+                test.chaincert_id.clone(),
+                Address::from_contract_id(&test.env, &test.wallet.contract_id),
+                BytesN::from_array(&test.env, &CONTENT_HASH),
+                test.distributor_contract.clone(),
+                org1.clone()
+            )
+                .into_val(&test.env)
+        )]
+    );
+    test.wallet.transfer_chaincert(
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &destination.contract_id),
+    );
+
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+    let chaincert = destination.get_chaincert(&test.chaincert_id, &None);
+    assert!(chaincert.transferable);
+    assert_eq!(chaincert.org_id, org1);
+    assert_eq!(chaincert.distributor_contract, test.distributor_contract);
+    assert_eq!(chaincert.tags.len(), 0);
+}
+
+#[test]
+fn test_transfer_chaincert_of_contract_distributor() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let content_hash = BytesN::from_array(&test.env, &CONTENT_HASH);
+    let distributor_id = test
+        .env
+        .register_contract(None, distributor_contract::DistributorContract {});
+    let distributor =
+        distributor_contract::DistributorContractClient::new(&test.env, &distributor_id);
+    let destination = create_wallet(&test.env, &Address::random(&test.env));
+    destination.add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    distributor.deposit(
+        &test.wallet.contract_id,
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &content_hash,
+        &org1,
+    );
+
+    distributor.approve_transfer(
+        &destination.contract_id,
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &test.wallet.contract_id),
+        &content_hash,
+        &org1,
+    );
+    test.wallet.transfer_chaincert(
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &destination.contract_id),
+    );
+
+    assert!(test
+        .env
+        .recorded_top_authorizations()
+        .iter()
+        .all(|(address, _, _, _)| address == &test.owner));
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+    assert_eq!(
+        destination
+            .get_chaincert(&test.chaincert_id, &None)
+            .distributor_contract,
+        Address::from_contract_id(&test.env, &distributor_id)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(52))")]
+fn test_transfer_chaincert_when_not_approved() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let destination = create_wallet(&test.env, &Address::random(&test.env));
+    destination.add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &true,
+    );
+
+    test.wallet.transfer_chaincert(
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &destination.contract_id),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(52))")]
+fn test_receive_chaincert_when_content_differs_from_approval() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let source_wallet = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    let chaincert = Chaincert::new(
+        &test.env,
+        test.cids.get_unchecked(0).unwrap(),
+        BytesN::from_array(&test.env, &[2; 32]),
+        test.distributor_contract.clone(),
+        org1.clone(),
+        1680105831,
+        OptionalTimestamp::None,
+        OptionalTimestamp::None,
+        Bytes::new(&test.env),
+        true,
+        false,
+        false,
+    );
+    test.wallet.approve_transfer(
+        &test.chaincert_id,
+        &source_wallet,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+    );
+
+    test.wallet
+        .receive_chaincert(&source_wallet, &test.chaincert_id, &chaincert);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(44))")]
+fn test_transfer_chaincert_when_not_transferable() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let destination = create_wallet(&test.env, &Address::random(&test.env));
+    destination.add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.transfer_chaincert(
+        &test.chaincert_id,
+        &Address::from_contract_id(&test.env, &destination.contract_id),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(49))")]
+fn test_transfer_chaincert_to_an_account() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &true,
+    );
+
+    let account = Address::try_from_val(
+        &test.env,
+        &ScVal::Address(ScAddress::Account(AccountId(
+            PublicKey::PublicKeyTypeEd25519(Uint256([1; 32])),
+        ))),
+    )
+    .unwrap();
+    test.wallet.transfer_chaincert(&test.chaincert_id, &account);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(21))")]
+fn test_receive_chaincert_with_invalid_cid() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let source_wallet = Address::random(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    let chaincert = Chaincert::new(
+        &test.env,
+        "forged".into_val(&test.env),
+        BytesN::from_array(&test.env, &CONTENT_HASH),
+        test.distributor_contract.clone(),
+        org1.clone(),
+        1680105831,
+        OptionalTimestamp::None,
+        OptionalTimestamp::None,
+        Bytes::new(&test.env),
+        true,
+        false,
+        false,
+    );
+
+    test.wallet.approve_transfer(
+        &test.chaincert_id,
+        &source_wallet,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+    );
+
+    test.wallet
+        .receive_chaincert(&source_wallet, &test.chaincert_id, &chaincert);
+}
+
+#[test]
+fn test_audit_log() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let verifier = Address::random(&test.env);
+    let chaincert_ids = vec![&test.env, test.chaincert_id.clone()];
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1680105831);
+    test.wallet
+        .grant_access(&verifier, &chaincert_ids, &1690000000);
+    let presentation_id = test.wallet.create_presentation(
+        &chaincert_ids,
+        &verifier,
+        &"nonce".into_val(&test.env),
+        &1690000000,
+    );
+    assert_eq!(test.wallet.get_audit_log(&0, &10).total, 0);
+
+    test.wallet.get_shared_chaincerts(&verifier);
+    assert!(test.wallet.verify_presentation(&presentation_id, &verifier));
+    assert!(!test
+        .wallet
+        .verify_presentation(&presentation_id, &Address::random(&test.env)));
+
+    let audit_log = test.wallet.get_audit_log(&0, &10);
+    assert_eq!(audit_log.total, 2);
+    let entry = audit_log.entries.get_unchecked(0).unwrap();
+    assert_eq!(entry.verifier, verifier);
+    assert_eq!(entry.action, AuditAction::AccessGrantUsed);
+    assert_eq!(entry.chaincert_ids, chaincert_ids);
+    assert_eq!(entry.timestamp, 1680105831);
+    assert_eq!(
+        audit_log.entries.get_unchecked(1).unwrap().action,
+        AuditAction::PresentationVerified
+    );
+    assert_eq!(test.wallet.get_audit_log(&1, &10).entries.len(), 1);
+}
+
+#[test]
+fn test_verify_content() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    assert!(test.wallet.verify_content(
+        &test.chaincert_id,
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &None
+    ));
+    assert!(!test.wallet.verify_content(
+        &test.chaincert_id,
+        &BytesN::from_array(&test.env, &[2; 32]),
+        &None
+    ));
+}
+
+#[test]
+fn test_version() {
+    let test = ChaincertWalletTest::setup();
+
+    let version = test.wallet.version();
+    assert_eq!(
+        version.contract,
+        Bytes::from_slice(&test.env, env!("CARGO_PKG_VERSION").as_bytes())
+    );
+    assert_eq!(version.storage, version.latest_storage);
+    assert_eq!(version.latest_storage, 2);
+}
+
+#[test]
+fn test_expiration_evaluated_at_read_time() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert!(!test.wallet.get_chaincert(&test.chaincert_id, &None).expired);
+
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+    assert!(test.wallet.get_chaincert(&test.chaincert_id, &None).expired);
+    assert!(
+        test.wallet
+            .get_chaincerts()
+            .get_unchecked(0)
+            .unwrap()
+            .expired
+    );
+}
+
+#[test]
+fn test_sweep() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let sweeper = Address::random(&test.env);
+    let token_id = test
+        .env
+        .register_contract(None, token_contract::TokenContract {});
+    let token = token_contract::TokenContractClient::new(&test.env, &token_id);
+    let wallet_address = Address::from_contract_id(&test.env, &test.wallet.contract_id);
+    token.mint(&wallet_address, &15);
+    test.wallet
+        .set_sweep_bounty(&Address::from_contract_id(&test.env, &token_id), &10);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    for chaincert_id in [test.chaincert_id.clone(), "CHAINCERT2".into_val(&test.env)] {
+        test.wallet.deposit_chaincert(
+            &chaincert_id,
+            &test.cids.get_unchecked(0).unwrap(),
+            &BytesN::from_array(&test.env, &CONTENT_HASH),
+            &test.distributor_contract,
+            &org1,
+            &1680105831,
+            &OptionalTimestamp::Some(1711662757),
+            &OptionalTimestamp::None,
+            &Bytes::new(&test.env),
+            &false,
+        );
+        test.wallet.revoke_chaincert(
+            &chaincert_id,
+            &test.distributor_contract,
+            &org1,
+            &RevocationReason::Unspecified,
+        );
+    }
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1711662757 + 2592000);
+
+    test.wallet.sweep(&sweeper, &test.chaincert_id);
+    assert_eq!(token.balance(&sweeper), 10);
+    assert_eq!(token.balance(&wallet_address), 5);
+
+    test.wallet
+        .sweep(&sweeper, &"CHAINCERT2".into_val(&test.env));
+    assert_eq!(token.balance(&sweeper), 10);
+    assert_eq!(test.wallet.chaincerts_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(45))")]
+fn test_sweep_when_chaincert_not_sweepable() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::Some(1711662757),
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    test.env.ledger().with_mut(|li| li.timestamp = 1711662757);
+
+    test.wallet
+        .sweep(&Address::random(&test.env), &test.chaincert_id);
+}
+
+#[test]
+fn test_suspend_organization() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet.suspend_organization(&org1);
+    assert!(test.wallet.get_organization(&org1).suspended);
+    assert!(!test.wallet.is_organization_authorized(&org1));
+    assert_eq!(test.wallet.acl_count(), 1);
+
+    test.wallet.resume_organization(&org1);
+    assert!(test.wallet.is_organization_authorized(&org1));
+    let events = test.env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 2..),
+        vec![
+            &test.env,
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("suspend"), test.owner.clone(), org1.clone()).into_val(&test.env),
+                ().into_val(&test.env)
+            ),
+            (
+                test.wallet.contract_id.clone(),
+                (Symbol::short("resume"), test.owner.clone(), org1.clone()).into_val(&test.env),
+                ().into_val(&test.env)
+            )
+        ]
+    );
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert!(test.wallet.get_chaincert(&test.chaincert_id, &None).revoked);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(46))")]
+fn test_revoke_chaincert_when_organization_suspended() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet.suspend_organization(&org1);
+
+    test.wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+}
+
+#[test]
+fn test_chaincert_note() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    let note: Bytes = "Renew before the next job application".into_val(&test.env);
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    assert_eq!(
+        test.wallet.get_chaincert_note(&test.chaincert_id),
+        Bytes::new(&test.env)
+    );
+
+    test.wallet.set_chaincert_note(&test.chaincert_id, &note);
+    assert_eq!(test.wallet.get_chaincert_note(&test.chaincert_id), note);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.owner.clone(),
+            test.wallet.contract_id.clone(),
+            Symbol::new(&test.env, "get_chaincert_note"),
+            (test.chaincert_id.clone(),).into_val(&test.env)
+        )]
+    );
+
+    test.wallet
+        .set_chaincert_note(&test.chaincert_id, &Bytes::new(&test.env));
+    assert_eq!(
+        test.wallet.get_chaincert_note(&test.chaincert_id),
+        Bytes::new(&test.env)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(47))")]
+fn test_set_chaincert_note_when_too_long() {
+    let test = ChaincertWalletTest::setup();
+
+    test.wallet
+        .set_chaincert_note(&test.chaincert_id, &Bytes::from_array(&test.env, &[0; 257]));
+}
+
+#[test]
+fn test_export_snapshot() {
+    let test = ChaincertWalletTest::setup();
+    let snapshot = test.wallet.export_snapshot();
+    assert_eq!(snapshot.owner, test.owner);
+    assert_eq!(snapshot.acl.len(), 0);
+    assert_eq!(snapshot.chaincerts.len(), 0);
+
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    test.wallet
+        .set_chaincert_visibility(&test.chaincert_id, &Visibility::Private);
+
+    let snapshot = test.wallet.export_snapshot();
+    assert_eq!(snapshot.acl, test.wallet.get_access_control_list());
+    assert_eq!(
+        snapshot.chaincerts,
+        map![
+            &test.env,
+            (
+                test.chaincert_id.clone(),
+                test.wallet
+                    .get_chaincert(&test.chaincert_id, &Some(test.owner.clone()))
+            )
+        ]
+    );
+    assert_eq!(snapshot.metadata, test.wallet.get_metadata());
+    assert_eq!(snapshot.version, test.wallet.version());
+}
+
+#[test]
+fn test_import_snapshot() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    let snapshot = test.wallet.export_snapshot();
+
+    let new_wallet = create_wallet(&test.env, &test.owner);
+    new_wallet.import_snapshot(&snapshot);
+
+    assert_eq!(new_wallet.get_access_control_list(), snapshot.acl);
+    let chaincert = new_wallet.get_chaincert(&test.chaincert_id, &None);
+    assert!(chaincert.restored);
+    assert_eq!(chaincert.org_id, org1);
+    assert_eq!(chaincert.distributor_contract, test.distributor_contract);
+    assert_eq!(
+        new_wallet
+            .get_chaincert_history(&test.chaincert_id, &None)
+            .get_unchecked(0)
+            .unwrap()
+            .action,
+        HistoryAction::Restored
+    );
+    assert!(!new_wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    assert_eq!(
+        new_wallet
+            .get_chaincerts_by_status(&CertStatus::Restored)
+            .len(),
+        1
+    );
+
+    new_wallet.attest_chaincert(&test.chaincert_id, &test.distributor_contract, &org1);
+    assert_eq!(
+        test.env.recorded_top_authorizations(),
+        std::vec![(
+            test.distributor_contract.clone(),
+            new_wallet.contract_id.clone(),
+            Symbol::new(&test.env, "attest_chaincert"),
+            (
+                test.chaincert_id.clone(),
+                test.distributor_contract.clone(),
+                org1.clone()
+            )
+                .into_val(&test.env)
+        )]
+    );
+    assert!(!new_wallet.get_chaincert(&test.chaincert_id, &None).restored);
+    assert!(new_wallet.is_chaincert_valid(&test.chaincert_id, &None));
+    new_wallet.revoke_chaincert(
+        &test.chaincert_id,
+        &test.distributor_contract,
+        &org1,
+        &RevocationReason::Unspecified,
+    );
+    assert!(new_wallet.get_chaincert(&test.chaincert_id, &None).revoked);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(8))")]
+fn test_import_snapshot_with_chaincert_of_organization_not_in_acl() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+    let mut snapshot = test.wallet.export_snapshot();
+    snapshot.acl = Vec::new(&test.env);
+
+    let new_wallet = create_wallet(&test.env, &test.owner);
+    new_wallet.import_snapshot(&snapshot);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(51))")]
+fn test_attest_chaincert_when_not_restored() {
+    let test = ChaincertWalletTest::setup();
+    let org1 = test.organizations.get_unchecked(0).unwrap();
+    test.wallet
+        .add_organization(&org1, &OptionalTimestamp::None);
+    test.wallet.deposit_chaincert(
+        &test.chaincert_id,
+        &test.cids.get_unchecked(0).unwrap(),
+        &BytesN::from_array(&test.env, &CONTENT_HASH),
+        &test.distributor_contract,
+        &org1,
+        &1680105831,
+        &OptionalTimestamp::None,
+        &OptionalTimestamp::None,
+        &Bytes::new(&test.env),
+        &false,
+    );
+
+    test.wallet
+        .attest_chaincert(&test.chaincert_id, &test.distributor_contract, &org1);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(48))")]
+fn test_import_snapshot_into_not_empty_wallet() {
+    let test = ChaincertWalletTest::setup();
+    test.wallet.add_organization(
+        &test.organizations.get_unchecked(0).unwrap(),
+        &OptionalTimestamp::None,
+    );
+    let snapshot = test.wallet.export_snapshot();
+    test.wallet.import_snapshot(&snapshot);
+}
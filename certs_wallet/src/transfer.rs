@@ -0,0 +1,47 @@
+//! Module Transfer
+//!
+//! Module responsible of the approvals the distributor contracts give to a wallet to receive their
+//! transferable `Chaincerts` from another wallet. The approval is given in a call of its own, so a
+//! distributor that is a contract can authorize it by invoking the wallet directly.
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+
+use crate::{chaincert::Chaincert, error::ContractError, storage_types::DataKey};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The `Chaincert` a distributor contract allows the wallet to receive from a source wallet
+pub struct TransferApproval {
+    pub source_wallet: Address,
+    pub distributor_contract: Address,
+    pub org_id: Bytes,
+    /// The hash of the content of the `Chaincert`, the received `Chaincert` must match it
+    pub content_hash: BytesN<32>,
+}
+
+pub(crate) fn approve_transfer(env: &Env, chaincert_id: &Bytes, approval: &TransferApproval) {
+    env.storage()
+        .set(&DataKey::TransferApproval(chaincert_id.clone()), approval)
+}
+
+/// Consumes the approval to receive the `Chaincert` from the source wallet, failing when it was not
+/// approved by the distributor contract and organization that issued it
+pub(crate) fn consume_approval(
+    env: &Env,
+    source_wallet: &Address,
+    chaincert_id: &Bytes,
+    chaincert: &Chaincert,
+) {
+    let key = DataKey::TransferApproval(chaincert_id.clone());
+    let approval: TransferApproval = match env.storage().get(&key) {
+        Some(approval) => approval.unwrap(),
+        None => panic_with_error!(env, ContractError::TransferNotApproved),
+    };
+    if approval.source_wallet != source_wallet.clone()
+        || approval.distributor_contract != chaincert.distributor_contract
+        || approval.org_id != chaincert.org_id
+        || approval.content_hash != chaincert.content_hash
+    {
+        panic_with_error!(env, ContractError::TransferNotApproved)
+    }
+    env.storage().remove(&key)
+}